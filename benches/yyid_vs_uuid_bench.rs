@@ -1,5 +1,5 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use std::time::Duration;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::{hint::black_box, time::Duration};
 
 use uuid::Uuid;
 use yyid::Yyid;
@@ -34,9 +34,39 @@ fn random_id_bench(c: &mut Criterion) {
     group.finish()
 }
 
+fn batch_id_bench(c: &mut Criterion) {
+    const COUNT: usize = 1000;
+
+    let mut group = c.benchmark_group("batch");
+    group.throughput(Throughput::Elements(COUNT as u64));
+    group.bench_function("uuid", |b| {
+        b.iter(|| {
+            black_box((0..COUNT).map(|_| Uuid::new_v4()).collect::<Vec<_>>());
+        })
+    });
+    group.bench_function("yyid", |b| {
+        b.iter(|| {
+            black_box((0..COUNT).map(|_| Yyid::new()).collect::<Vec<_>>());
+        })
+    });
+    group.bench_function("yyid_fill", |b| {
+        b.iter(|| {
+            let mut yyids = vec![Yyid::nil(); COUNT];
+            Yyid::fill(&mut yyids);
+            black_box(yyids);
+        })
+    });
+    group.bench_function("yyid_new_batch", |b| {
+        b.iter(|| {
+            black_box(Yyid::new_batch::<COUNT>());
+        })
+    });
+    group.finish()
+}
+
 criterion_group!(
     name = benches;
     config = Criterion::default().measurement_time(Duration::from_secs(10));
-    targets = nil_id_bench, random_id_bench
+    targets = nil_id_bench, random_id_bench, batch_id_bench
 );
 criterion_main!(benches);
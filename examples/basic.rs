@@ -3,31 +3,55 @@ use yyid::*;
 fn main() {
     let yyid = Yyid::nil();
     println!("\nUsing the Yyid::nil()");
-    println!("[Display] [yyid:X]   {yyid:X}");
-    println!("[Display] [Hyphen]   {}", yyid.as_hyphenated());
-    println!("[Display] [Simple]   {}", yyid.as_simple());
-    println!("[Display] [URN]      {}", yyid.as_urn());
-    println!("[Display] [Braced]   {}", yyid.as_braced());
+    println!("[Display] [yyid:X]    {yyid:X}");
+    println!("[Display] [Hyphen]    {}", yyid.as_hyphenated());
+    println!("[Display] [Simple]    {}", yyid.as_simple());
+    println!("[Display] [URN]       {}", yyid.as_urn());
+    println!("[Display] [Braced]    {}", yyid.as_braced());
+    println!("[Display] [Base32]    {}", yyid.as_base32());
+    println!("[Display] [Base32Hex] {}", yyid.as_base32hex());
+    println!("[Display] [Base58]    {}", yyid.as_base58());
+    println!("[Display] [Base62]    {}", yyid.as_base62());
+    println!("[Display] [Base64Url] {}", yyid.as_base64url());
+    println!("[Display] [Prefixed]  {}", fmts::Prefixed::new("usr", yyid).unwrap());
     println!("---------------------------------------------------------------------");
-    println!("[Debug]   [yyid:?]   {yyid:?}");
-    println!("[Debug]   [Hyphen]   {:?}", yyid.as_hyphenated());
-    println!("[Debug]   [Simple]   {:?}", yyid.as_simple());
-    println!("[Debug]   [URN]      {:?}", yyid.as_urn());
-    println!("[Debug]   [Braced]   {:?}", yyid.as_braced());
+    println!("[Debug]   [yyid:?]    {yyid:?}");
+    println!("[Debug]   [Hyphen]    {:?}", yyid.as_hyphenated());
+    println!("[Debug]   [Simple]    {:?}", yyid.as_simple());
+    println!("[Debug]   [URN]       {:?}", yyid.as_urn());
+    println!("[Debug]   [Braced]    {:?}", yyid.as_braced());
+    println!("[Debug]   [Base32]    {:?}", yyid.as_base32());
+    println!("[Debug]   [Base32Hex] {:?}", yyid.as_base32hex());
+    println!("[Debug]   [Base58]    {:?}", yyid.as_base58());
+    println!("[Debug]   [Base62]    {:?}", yyid.as_base62());
+    println!("[Debug]   [Base64Url] {:?}", yyid.as_base64url());
+    println!("[Debug]   [Prefixed]  {:?}", fmts::Prefixed::new("usr", yyid).unwrap());
 
     println!("\n=====================================================================");
 
     let yyid = Yyid::new();
     println!("\nUsing the Yyid::new()");
-    println!("[Display] [yyid:X]   {yyid:X}");
-    println!("[Display] [Hyphen]   {}", yyid.as_hyphenated());
-    println!("[Display] [Simple]   {}", yyid.as_simple());
-    println!("[Display] [URN]      {}", yyid.as_urn());
-    println!("[Display] [Braced]   {}", yyid.as_braced());
+    println!("[Display] [yyid:X]    {yyid:X}");
+    println!("[Display] [Hyphen]    {}", yyid.as_hyphenated());
+    println!("[Display] [Simple]    {}", yyid.as_simple());
+    println!("[Display] [URN]       {}", yyid.as_urn());
+    println!("[Display] [Braced]    {}", yyid.as_braced());
+    println!("[Display] [Base32]    {}", yyid.as_base32());
+    println!("[Display] [Base32Hex] {}", yyid.as_base32hex());
+    println!("[Display] [Base58]    {}", yyid.as_base58());
+    println!("[Display] [Base62]    {}", yyid.as_base62());
+    println!("[Display] [Base64Url] {}", yyid.as_base64url());
+    println!("[Display] [Prefixed]  {}", fmts::Prefixed::new("usr", yyid).unwrap());
     println!("---------------------------------------------------------------------");
-    println!("[Debug]   [yyid:?]   {yyid:?}");
-    println!("[Debug]   [Hyphen]   {:?}", yyid.as_hyphenated());
-    println!("[Debug]   [Simple]   {:?}", yyid.as_simple());
-    println!("[Debug]   [URN]      {:?}", yyid.as_urn());
-    println!("[Debug]   [Braced]   {:?}", yyid.as_braced());
+    println!("[Debug]   [yyid:?]    {yyid:?}");
+    println!("[Debug]   [Hyphen]    {:?}", yyid.as_hyphenated());
+    println!("[Debug]   [Simple]    {:?}", yyid.as_simple());
+    println!("[Debug]   [URN]       {:?}", yyid.as_urn());
+    println!("[Debug]   [Braced]    {:?}", yyid.as_braced());
+    println!("[Debug]   [Base32]    {:?}", yyid.as_base32());
+    println!("[Debug]   [Base32Hex] {:?}", yyid.as_base32hex());
+    println!("[Debug]   [Base58]    {:?}", yyid.as_base58());
+    println!("[Debug]   [Base62]    {:?}", yyid.as_base62());
+    println!("[Debug]   [Base64Url] {:?}", yyid.as_base64url());
+    println!("[Debug]   [Prefixed]  {:?}", fmts::Prefixed::new("usr", yyid).unwrap());
 }
@@ -0,0 +1,356 @@
+//! Buffered generation of YYIDs, amortizing calls to the operating system
+//!
+//! A [`BufferedGenerator`] retrieves 4 KiB of randomness from the operating
+//! system at once and hands it out in 16 byte slices, so only every 256th YYID
+//! costs a syscall. The randomness is still of OS quality.
+//!
+//! On Unix a forked child process never reuses the buffer of its parent: a
+//! fork handler invalidates all buffers in the child, which then refill from
+//! the operating system. Bytes are wiped from the buffer once handed out.
+//! Until that handler is registered, YYIDs come from the operating system
+//! directly.
+//!
+//! With the `buffered` feature enabled, [`Yyid::new`] and friends draw from a
+//! thread-local [`BufferedGenerator`].
+
+use crate::{
+    bytes_of_mut,
+    std::{
+        fmt,
+        sync::atomic::{AtomicU8, AtomicUsize, Ordering},
+    },
+    Error, ErrorKind, Yyid, YyidGenerator,
+};
+
+const BUFFER_SIZE: usize = 4096;
+
+/// Incremented in the child process after every fork.
+static FORK_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A generator handing out YYIDs from a buffer of OS randomness.
+///
+/// It deliberately does not implement [`Clone`], as a copy would hand out the
+/// same YYIDs as the original.
+///
+/// ### Example
+/// ```rust
+/// use yyid::buffered::BufferedGenerator;
+///
+/// let mut generator = BufferedGenerator::new();
+/// let yyid = generator.generate();
+/// assert_ne!(yyid, generator.generate());
+/// ```
+pub struct BufferedGenerator {
+    buffer: [u8; BUFFER_SIZE],
+    /// Index of the next unused byte; `BUFFER_SIZE` if the buffer is empty.
+    position: usize,
+    /// Value of [`FORK_COUNTER`] at the time of the last refill.
+    forks: usize,
+}
+
+impl BufferedGenerator {
+    /// Number of bytes retrieved from the operating system at once.
+    pub const BUFFER_SIZE: usize = BUFFER_SIZE;
+
+    /// Creates a generator with an empty buffer; it gets filled on first use.
+    pub const fn new() -> Self {
+        BufferedGenerator {
+            buffer: [0; BUFFER_SIZE],
+            position: BUFFER_SIZE,
+            forks: 0,
+        }
+    }
+
+    /// Returns the next random YYID.
+    ///
+    /// ### Panics
+    /// Panics if the source of randomness fails; use
+    /// [`BufferedGenerator::try_generate`] to handle this case.
+    pub fn generate(&mut self) -> Yyid {
+        self.try_generate()
+            .unwrap_or_else(|err| panic!("could not create a new YYID: {}", err))
+    }
+
+    /// Returns the next random YYID, or an error if the buffer needed a refill
+    /// and no random bytes could be retrieved.
+    pub fn try_generate(&mut self) -> Result<Yyid, Error> {
+        let mut yyid = Yyid::nil();
+        self.try_fill(crate::std::slice::from_mut(&mut yyid))?;
+        Ok(yyid)
+    }
+
+    /// Overwrites every YYID in `yyids` with a new random one.
+    ///
+    /// Requests of at least [`BufferedGenerator::BUFFER_SIZE`] bytes bypass the
+    /// buffer and go to the operating system directly.
+    pub fn try_fill(&mut self, yyids: &mut [Yyid]) -> Result<(), Error> {
+        let mut dest = bytes_of_mut(yyids);
+        if dest.len() >= BUFFER_SIZE {
+            return getrandom::fill(dest).map_err(Error::entropy);
+        }
+
+        while !dest.is_empty() {
+            if self.position == BUFFER_SIZE || self.forks != FORK_COUNTER.load(Ordering::Relaxed) {
+                if !register_fork_handler()? {
+                    // a fork could not be detected yet, so don't buffer anything
+                    return getrandom::fill(dest).map_err(Error::entropy);
+                }
+                self.refill()?;
+            }
+
+            let len = dest.len().min(BUFFER_SIZE - self.position);
+            let src = &mut self.buffer[self.position..self.position + len];
+            let (head, tail) = dest.split_at_mut(len);
+            head.copy_from_slice(src);
+            src.fill(0);
+
+            self.position += len;
+            dest = tail;
+        }
+        Ok(())
+    }
+
+    fn refill(&mut self) -> Result<(), Error> {
+        // read the counter before filling, so a fork racing with the refill
+        // is still detected on the next call
+        let forks = FORK_COUNTER.load(Ordering::Relaxed);
+
+        self.position = BUFFER_SIZE;
+        getrandom::fill(&mut self.buffer).map_err(Error::entropy)?;
+
+        self.position = 0;
+        self.forks = forks;
+        Ok(())
+    }
+}
+
+impl YyidGenerator for BufferedGenerator {
+    #[inline]
+    fn try_generate(&mut self) -> Result<Yyid, Error> {
+        BufferedGenerator::try_generate(self)
+    }
+}
+
+impl Default for BufferedGenerator {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for BufferedGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // never print the buffered randomness
+        f.debug_struct("BufferedGenerator")
+            .field("available", &(BUFFER_SIZE - self.position))
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(unix)]
+const UNREGISTERED: u8 = 0;
+#[cfg(unix)]
+const REGISTERING: u8 = 1;
+#[cfg(unix)]
+const REGISTERED: u8 = 2;
+
+/// Whether [`on_fork_in_child`] got registered as a fork handler.
+#[cfg(unix)]
+static FORK_HANDLER: AtomicU8 = AtomicU8::new(UNREGISTERED);
+
+#[cfg(unix)]
+extern "C" fn on_fork_in_child() {
+    FORK_COUNTER.fetch_add(1, Ordering::Relaxed);
+    // the fork may have happened before the registering thread stored this,
+    // and that thread does not exist in the child
+    FORK_HANDLER.store(REGISTERED, Ordering::Release);
+}
+
+/// Registers the fork handler once per process.
+///
+/// Returns `false` while another thread is registering it, or if the process
+/// got forked during the registration; buffering is unsafe until it is done.
+#[cfg(unix)]
+fn register_fork_handler() -> Result<bool, Error> {
+    match FORK_HANDLER.compare_exchange(UNREGISTERED, REGISTERING, Ordering::Acquire, Ordering::Acquire) {
+        Ok(_) => {
+            // SAFETY: the handler only touches atomics, which is async-signal-safe
+            let ret = unsafe { libc::pthread_atfork(None, None, Some(on_fork_in_child)) };
+            if ret != 0 {
+                // let the next refill try again
+                FORK_HANDLER.store(UNREGISTERED, Ordering::Release);
+                return Err(Error(ErrorKind::ForkHandler { code: ret }));
+            }
+            FORK_HANDLER.store(REGISTERED, Ordering::Release);
+            Ok(true)
+        }
+        Err(state) => Ok(state == REGISTERED),
+    }
+}
+
+#[cfg(not(unix))]
+fn register_fork_handler() -> Result<bool, Error> {
+    Ok(true)
+}
+
+#[cfg(feature = "buffered")]
+std::thread_local! {
+    static THREAD_GENERATOR: crate::std::cell::RefCell<BufferedGenerator> = const {
+        crate::std::cell::RefCell::new(BufferedGenerator::new())
+    };
+}
+
+/// Fills `yyids` from the thread-local generator.
+#[cfg(feature = "buffered")]
+pub(crate) fn thread_fill(yyids: &mut [Yyid]) -> Result<(), Error> {
+    THREAD_GENERATOR.with(|cell| cell.borrow_mut().try_fill(yyids))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::{
+        collections::HashSet,
+        sync::{Mutex, MutexGuard, PoisonError},
+    };
+    use pretty_assertions::assert_eq;
+
+    /// Held by tests which inspect the buffer, or change the fork handler state.
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock() -> MutexGuard<'static, ()> {
+        LOCK.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[test]
+    fn test_generate_unique() {
+        let mut generator = BufferedGenerator::new();
+        let set: HashSet<Yyid> = (0..1000).map(|_| generator.generate()).collect();
+
+        assert_eq!(set.len(), 1000);
+        assert!(!set.contains(&Yyid::nil()));
+    }
+
+    #[test]
+    fn test_fill_across_refills() {
+        let mut generator = BufferedGenerator::new();
+        let _ = generator.generate();
+
+        let mut small = [Yyid::nil(); 100];
+        let mut large = [Yyid::nil(); 300];
+        generator.try_fill(&mut small).unwrap();
+        generator.try_fill(&mut large).unwrap();
+
+        let set: HashSet<&Yyid> = small.iter().chain(large.iter()).collect();
+        assert_eq!(set.len(), 400);
+        assert!(!set.contains(&Yyid::nil()));
+    }
+
+    #[test]
+    fn test_bytes_wiped_after_use() {
+        let _lock = lock();
+        let mut generator = BufferedGenerator::new();
+        let _ = generator.generate();
+
+        assert_eq!(generator.position, 16);
+        assert!(generator.buffer[..16].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_fork_invalidates_buffer() {
+        let _lock = lock();
+        let mut generator = BufferedGenerator::new();
+        let _ = generator.generate();
+        let unused = generator.buffer[16..32].to_vec();
+
+        // what the fork handler does in the child
+        FORK_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        let yyid = generator.generate();
+        assert_eq!(generator.position, 16);
+        assert_ne!(&yyid.as_bytes()[..], &unused[..]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_forked_child_does_not_reuse_buffer() {
+        let mut generator = BufferedGenerator::new();
+        let _ = generator.generate();
+
+        let mut fds = [0; 2];
+        // SAFETY: `fds` has room for both ends of the pipe
+        assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+        let [read_fd, write_fd] = fds;
+
+        // SAFETY: the child only generates a YYID, writes it and exits
+        let pid = unsafe { libc::fork() };
+        assert!(pid >= 0);
+        if pid == 0 {
+            let yyid = generator.generate();
+            // SAFETY: writes the 16 bytes of `yyid`, then exits without unwinding
+            unsafe {
+                libc::write(write_fd, yyid.as_bytes().as_ptr().cast(), 16);
+                libc::_exit(0);
+            }
+        }
+
+        let parent = generator.generate();
+        let mut child = [0u8; 16];
+        // SAFETY: `child` has room for the 16 bytes read
+        unsafe {
+            libc::close(write_fd);
+            assert_eq!(libc::read(read_fd, child.as_mut_ptr().cast(), 16), 16);
+            libc::close(read_fd);
+            libc::waitpid(pid, crate::std::ptr::null_mut(), 0);
+        }
+
+        assert_ne!(parent.as_bytes(), &child);
+        assert_ne!(child, [0; 16]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_registering_fork_handler_bypasses_buffer() {
+        let _lock = lock();
+        let _ = BufferedGenerator::new().generate();
+
+        FORK_HANDLER.store(REGISTERING, Ordering::Release);
+        let mut generator = BufferedGenerator::new();
+        let yyid = generator.try_generate();
+        FORK_HANDLER.store(REGISTERED, Ordering::Release);
+
+        assert!(!yyid.unwrap().is_nil());
+        assert_eq!(generator.position, BUFFER_SIZE);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_fork_while_registering_fork_handler() {
+        let _lock = lock();
+        let _ = BufferedGenerator::new().generate();
+
+        // as if the registering thread got forked away before it stored `REGISTERED`
+        FORK_HANDLER.store(REGISTERING, Ordering::Release);
+        // SAFETY: the child only generates a YYID and exits
+        let pid = unsafe { libc::fork() };
+        assert!(pid >= 0);
+        if pid == 0 {
+            // SAFETY: kills the child instead of letting it hang, exits without unwinding
+            unsafe {
+                libc::alarm(10);
+                let mut generator = BufferedGenerator::new();
+                let ok = generator.try_generate().is_ok_and(|yyid| !yyid.is_nil())
+                    && FORK_HANDLER.load(Ordering::Acquire) == REGISTERED
+                    && generator.position == 16;
+                libc::_exit(if ok { 0 } else { 1 });
+            }
+        }
+        FORK_HANDLER.store(REGISTERED, Ordering::Release);
+
+        let mut status = 0;
+        // SAFETY: `status` is a valid pointer for the exit status
+        assert_eq!(unsafe { libc::waitpid(pid, &mut status, 0) }, pid);
+        assert!(libc::WIFEXITED(status));
+        assert_eq!(libc::WEXITSTATUS(status), 0);
+    }
+}
@@ -0,0 +1,116 @@
+//! Reversible mapping between sequence numbers and YYIDs
+//!
+//! A [`YyidCipher`] encrypts a 128-bit value with AES-256, a keyed permutation
+//! of all 128-bit values. Sequential database keys can be exposed as opaque,
+//! random-looking YYIDs and mapped back without a lookup table.
+//!
+//! The YYIDs are only as secret as the key: anyone holding it can recover the
+//! sequence numbers. Decrypting a YYID with the wrong key does not fail, but
+//! yields an unrelated value, so check that it refers to an existing row.
+
+use crate::{std::fmt, Error, ErrorKind, Yyid};
+use aes::{
+    cipher::{BlockDecrypt, BlockEncrypt, KeyInit},
+    Aes256, Block,
+};
+
+/// Encrypts numbers into YYIDs and decrypts them back.
+///
+/// ### Example
+/// ```rust
+/// use yyid::cipher::YyidCipher;
+///
+/// let cipher = YyidCipher::new(&[7; 32]); // anyone with this key can decrypt the YYIDs
+///
+/// let yyid = cipher.encrypt_u64(42);
+/// assert_eq!(cipher.decrypt_u64(yyid), Ok(42));
+/// assert_ne!(yyid, cipher.encrypt_u64(43));
+/// ```
+#[derive(Clone)]
+pub struct YyidCipher(Aes256);
+
+impl YyidCipher {
+    /// Creates a cipher from a 32 byte key.
+    pub fn new(key: &[u8; 32]) -> Self {
+        YyidCipher(Aes256::new(key.into()))
+    }
+
+    /// Encrypts `value` into a YYID.
+    pub fn encrypt(&self, value: u128) -> Yyid {
+        let mut block = Block::from(value.to_be_bytes());
+        self.0.encrypt_block(&mut block);
+        Yyid(block.into())
+    }
+
+    /// Decrypts a YYID created by [`YyidCipher::encrypt`] back into its value.
+    pub fn decrypt(&self, yyid: Yyid) -> u128 {
+        let mut block = Block::from(yyid.0);
+        self.0.decrypt_block(&mut block);
+        u128::from_be_bytes(block.into())
+    }
+
+    /// Encrypts a 64-bit `value` into a YYID.
+    #[inline]
+    pub fn encrypt_u64(&self, value: u64) -> Yyid {
+        self.encrypt(value.into())
+    }
+
+    /// Decrypts a YYID created by [`YyidCipher::encrypt_u64`] back into its
+    /// value.
+    ///
+    /// Fails if the decrypted value does not fit into a `u64`, which is almost
+    /// certainly the case for YYIDs created otherwise or with another key.
+    pub fn decrypt_u64(&self, yyid: Yyid) -> Result<u64, Error> {
+        u64::try_from(self.decrypt(yyid)).map_err(|_| Error(ErrorKind::CipherRange))
+    }
+}
+
+impl fmt::Debug for YyidCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // never print the key
+        f.debug_struct("YyidCipher").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::string::ToString;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    const KEY: [u8; 32] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11,
+        0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+    ];
+
+    #[test]
+    fn test_aes256_vector() {
+        // FIPS-197, appendix C.3
+        let cipher = YyidCipher::new(&KEY);
+        let yyid = cipher.encrypt(0x00112233_44556677_8899aabb_ccddeeff);
+
+        assert_eq!(yyid.to_string(), "8ea2b7ca-5167-45bf-eafc-49904b496089");
+        assert_eq!(cipher.decrypt(yyid), 0x00112233_44556677_8899aabb_ccddeeff);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let cipher = YyidCipher::new(&KEY);
+
+        for value in (0..1000).chain([u64::MAX - 1, u64::MAX]) {
+            let yyid = cipher.encrypt_u64(value);
+            assert_eq!(cipher.decrypt_u64(yyid), Ok(value));
+            assert_ne!(yyid, cipher.encrypt_u64(value ^ 1));
+        }
+        assert_eq!(cipher.decrypt(cipher.encrypt(u128::MAX)), u128::MAX);
+    }
+
+    #[test]
+    fn test_decrypt_u64_out_of_range() {
+        let cipher = YyidCipher::new(&KEY);
+        let other = YyidCipher::new(&[0; 32]);
+
+        let err = other.decrypt_u64(cipher.encrypt_u64(42)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::CipherRange);
+    }
+}
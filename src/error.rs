@@ -0,0 +1,272 @@
+//! Errors returned by fallible YYID operations
+
+use crate::std::fmt;
+
+/// An error that can occur while parsing or generating a [`Yyid`](crate::Yyid).
+///
+/// The [`Display`](fmt::Display) output describes what went wrong and what was
+/// expected instead, so it can be passed on to whoever supplied the input.
+/// Use [`Error::kind`] to inspect the details programmatically.
+///
+/// ### Example
+/// ```rust
+/// use yyid::{ErrorKind, Yyid};
+///
+/// let err = Yyid::parse_str("c49b79f5-22d4-dc42-f214-f4209c80d0x8").unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::Char { character: 'x', index: 34 });
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Error(pub(crate) ErrorKind);
+
+/// The details of an [`Error`].
+///
+/// All indices are byte offsets into the original input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input contained a character that is not allowed at its position.
+    Char {
+        /// The invalid character.
+        character: char,
+        /// The byte index of the invalid character.
+        index: usize,
+    },
+    /// A hyphenated input did not consist of 5 groups.
+    GroupCount {
+        /// The number of groups found.
+        count: usize,
+    },
+    /// A group of a hyphenated input had the wrong length.
+    GroupLength {
+        /// The zero-based number of the group.
+        group: usize,
+        /// The length of the group found.
+        len: usize,
+        /// The byte index at which the group starts.
+        index: usize,
+    },
+    /// The input had no hyphens, braces or URN prefix, but was not 32 bytes
+    /// long like a simple YYID.
+    Length {
+        /// The length of the input in bytes.
+        len: usize,
+    },
+    /// The input looked like a URN, but did not start with `urn:yyid:`.
+    UrnPrefix,
+    /// The input had an opening brace without a closing one, or vice versa.
+    Braces,
+    /// The input contained a character outside the alphabet of its format.
+    Digit {
+        /// The invalid character.
+        character: char,
+        /// The byte index of the invalid character.
+        index: usize,
+        /// The characters allowed by the format.
+        expected: &'static str,
+    },
+    /// The input encoded a value that does not fit into 128 bits.
+    Overflow,
+    /// The input did not have the length required by its format.
+    FormatLength {
+        /// The required length in bytes.
+        expected: usize,
+        /// The length of the input in bytes.
+        len: usize,
+    },
+    /// The configured source of randomness could not provide random bytes.
+    Entropy(EntropyError),
+    /// A decrypted YYID did not fit into the requested integer type.
+    CipherRange,
+    /// Too many YYIDs were generated within the same millisecond.
+    MonotonicOverflow,
+    /// The buffered generator could not register its fork handler, so it
+    /// cannot guarantee that a forked child does not reuse its buffer.
+    ForkHandler {
+        /// The error code returned by `pthread_atfork`.
+        code: i32,
+    },
+    /// The signature of a signed YYID did not match.
+    Signature,
+    /// The YYID is not a valid UUID, as its variant bits are not `10`.
+    UuidVariant {
+        /// The three most significant bits of octet 8.
+        bits: u8,
+    },
+    /// The YYID is not a valid UUID, as its version is not one of 1 to 8.
+    UuidVersion {
+        /// The four most significant bits of octet 6.
+        version: u8,
+    },
+    /// The prefix of a prefixed YYID was empty or too long.
+    PrefixLength {
+        /// The length of the prefix in bytes.
+        len: usize,
+    },
+    /// The input had no `_` between the prefix and the YYID.
+    Separator,
+    /// The prefix of a prefixed YYID was not the expected one.
+    PrefixMismatch,
+    /// A [`YyidGenerator`](crate::YyidGenerator) implemented outside this
+    /// crate failed; see [`Error::generator`].
+    Generator {
+        /// What went wrong, as given by the generator.
+        reason: &'static str,
+    },
+}
+
+/// The reason why the operating system could not provide random bytes.
+///
+/// The underlying error of the random number source is available through
+/// [`std::error::Error::source`] of [`Error`] with the `std` feature.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EntropyError(getrandom::Error);
+
+impl EntropyError {
+    /// Returns the OS error code, if the error came from the operating system.
+    pub fn raw_os_error(&self) -> Option<i32> {
+        self.0.raw_os_error()
+    }
+}
+
+impl fmt::Display for EntropyError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Expected lengths of the groups in a hyphenated YYID.
+pub(crate) const GROUP_LENGTHS: [usize; 5] = [8, 4, 4, 4, 12];
+
+impl Error {
+    /// Returns the details of what went wrong.
+    pub const fn kind(&self) -> ErrorKind {
+        self.0
+    }
+
+    /// Creates the error of a failed [`YyidGenerator`](crate::YyidGenerator),
+    /// for generators implemented outside this crate.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::{Error, ErrorKind, Yyid, YyidGenerator};
+    ///
+    /// struct Offline;
+    ///
+    /// impl YyidGenerator for Offline {
+    ///     fn try_generate(&mut self) -> Result<Yyid, Error> {
+    ///         Err(Error::generator("hardware token not connected"))
+    ///     }
+    /// }
+    ///
+    /// let err = Offline.try_generate().unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::Generator { reason: "hardware token not connected" });
+    /// ```
+    pub const fn generator(reason: &'static str) -> Self {
+        Error(ErrorKind::Generator { reason })
+    }
+
+    pub(crate) const fn entropy(err: getrandom::Error) -> Self {
+        Error(ErrorKind::Entropy(EntropyError(err)))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            ErrorKind::Char { character, index } => write!(
+                f,
+                "invalid character: expected an optional prefix of `urn:yyid:` followed by [0-9a-fA-F-], \
+                 found `{character}` at {index}"
+            ),
+            ErrorKind::GroupCount { count } => write!(f, "invalid group count: expected 5, found {count}"),
+            ErrorKind::GroupLength { group, len, index } => write!(
+                f,
+                "invalid group length in group {group}: expected {}, found {len} at {index}",
+                GROUP_LENGTHS[group]
+            ),
+            ErrorKind::Length { len } => {
+                write!(
+                    f,
+                    "invalid length: expected 32 bytes for a YYID without hyphens, found {len}"
+                )
+            }
+            ErrorKind::UrnPrefix => f.write_str("invalid URN prefix: expected `urn:yyid:`"),
+            ErrorKind::Braces => f.write_str("unbalanced braces: expected a hyphenated YYID enclosed in `{` and `}`"),
+            ErrorKind::Digit {
+                character,
+                index,
+                expected,
+            } => write!(
+                f,
+                "invalid character: expected {expected}, found `{character}` at {index}"
+            ),
+            ErrorKind::Overflow => f.write_str("value out of range: the input exceeds 128 bits"),
+            ErrorKind::FormatLength { expected, len } => {
+                write!(f, "invalid length: expected {expected} bytes, found {len}")
+            }
+            ErrorKind::Entropy(err) => write!(f, "could not retrieve random bytes: {err}"),
+            ErrorKind::CipherRange => {
+                f.write_str("decrypted value out of range: the YYID was not encrypted from a `u64` with this key")
+            }
+            ErrorKind::MonotonicOverflow => {
+                f.write_str("monotonic overflow: too many YYIDs generated within the same millisecond")
+            }
+            ErrorKind::ForkHandler { code } => {
+                write!(f, "could not register fork handler: `pthread_atfork` returned {code}")
+            }
+            ErrorKind::Signature => f.write_str("invalid signature: the YYID was forged or corrupted"),
+            ErrorKind::UuidVariant { bits } => write!(
+                f,
+                "not a valid UUID: expected the RFC 9562 variant `10x`, found `{bits:03b}`"
+            ),
+            ErrorKind::UuidVersion { version } => {
+                write!(f, "not a valid UUID: expected a version from 1 to 8, found {version}")
+            }
+            ErrorKind::PrefixLength { len } => {
+                write!(f, "invalid prefix length: expected 1 to 63 bytes, found {len}")
+            }
+            ErrorKind::Separator => f.write_str("missing separator: expected a prefix followed by `_`"),
+            ErrorKind::PrefixMismatch => f.write_str("prefix mismatch: the YYID belongs to a different type"),
+            ErrorKind::Generator { reason } => write!(f, "generator failed: {reason}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.0 {
+            ErrorKind::Entropy(EntropyError(err)) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{std::string::ToString, *};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_display() {
+        let err = Yyid::parse_str("c49b79f5-22d4-dc42-f214-f4209c80d04").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid group length in group 4: expected 12, found 11 at 24"
+        );
+
+        let err = Yyid::parse_str("c49b79f522d4dc42f214f4209c80d048c49b").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Length { len: 36 });
+        assert_eq!(
+            err.to_string(),
+            "invalid length: expected 32 bytes for a YYID without hyphens, found 36"
+        );
+
+        let err = Yyid::parse_str("{c49b79f5-22d4-dc42-f214-f4209c80d048").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unbalanced braces: expected a hyphenated YYID enclosed in `{` and `}`"
+        );
+    }
+}
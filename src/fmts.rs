@@ -2,20 +2,82 @@
 // A lot is copied from <https://github.com/uuid-rs/uuid/blob/master/src/adapter/mod.rs>
 
 use crate::{
+    parser::char_at,
     std::{borrow::Borrow, fmt, ptr, str},
-    Yyid,
+    Error, ErrorKind, Yyid,
 };
 
-const LOWER: [u8; 16] = [
+pub(crate) const LOWER: [u8; 16] = [
     b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'a', b'b', b'c', b'd', b'e', b'f',
 ];
 
-const UPPER: [u8; 16] = [
+pub(crate) const UPPER: [u8; 16] = [
     b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'A', b'B', b'C', b'D', b'E', b'F',
 ];
 
 const URN_PREFIX: &[u8; 9] = b"urn:yyid:";
 
+const BASE32_LOWER: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+
+const BASE32_UPPER: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+const BASE32HEX_LOWER: &[u8; 32] = b"0123456789abcdefghijklmnopqrstuv";
+
+const BASE32HEX_UPPER: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+
+const BASE32HEX_TABLE: &[u8; 256] = &{
+    let mut buf = radix_table(BASE32HEX_LOWER);
+    let mut i = 10;
+    while i < 32 {
+        buf[BASE32HEX_UPPER[i] as usize] = i as u8;
+        i += 1;
+    }
+    buf
+};
+
+const BASE58: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const BASE58_TABLE: &[u8; 256] = &radix_table(BASE58);
+
+const BASE62: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+const BASE62_TABLE: &[u8; 256] = &radix_table(BASE62);
+
+const BASE64_URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+pub(crate) const BASE64_URL_TABLE: &[u8; 256] = &radix_table(BASE64_URL);
+
+/// Like [`BASE64_URL_TABLE`], but also accepting the standard alphabet.
+const BASE64_ANY_TABLE: &[u8; 256] = &{
+    let mut buf = radix_table(BASE64_URL);
+    buf[b'+' as usize] = 62;
+    buf[b'/' as usize] = 63;
+    buf
+};
+
+/// Like [`BASE32_TABLE`], but only accepting the canonical lower-case digits.
+const BASE32_LOWER_TABLE: &[u8; 256] = &radix_table(BASE32_LOWER);
+
+/// Maps an ASCII byte to its Crockford Base32 value, or to `0xff` if it is not
+/// part of the alphabet. Decoding ignores case and treats `I`/`L` as `1` and
+/// `O` as `0`.
+const BASE32_TABLE: &[u8; 256] = &{
+    let mut buf = [0xff; 256];
+    let mut i = 0;
+    while i < 32 {
+        buf[BASE32_LOWER[i] as usize] = i as u8;
+        buf[BASE32_UPPER[i] as usize] = i as u8;
+        i += 1;
+    }
+    buf[b'I' as usize] = 1;
+    buf[b'i' as usize] = 1;
+    buf[b'L' as usize] = 1;
+    buf[b'l' as usize] = 1;
+    buf[b'O' as usize] = 0;
+    buf[b'o' as usize] = 0;
+    buf
+};
+
 /// Format a [`Yyid`] as a hyphenated string, like
 /// `c49b79f5-22d4-dc42-f214-f4209c80d048`.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -40,6 +102,79 @@ pub struct Urn(Yyid);
 #[repr(transparent)]
 pub struct Braced(Yyid);
 
+/// Format a [`Yyid`] as a [Crockford Base32](https://www.crockford.com/base32.html)
+/// string, like `64kdwza8pmvh1f457m42e81m28`.
+///
+/// The 26 characters sort in the same order as the YYIDs themselves.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Base32(Yyid);
+
+/// Format a [`Yyid`] as a Base32 string with the
+/// [base32hex](https://www.rfc-editor.org/rfc/rfc4648#section-7) alphabet
+/// `[0-9a-v]`, like `64jdsva8mkrh1f457k42e81k28`.
+///
+/// Like [`Base32`] this encodes the YYID as a single 128-bit number in 26
+/// digits without padding. As the alphabet is in ASCII order, the strings sort
+/// in the same order as the YYIDs themselves, which makes them suitable as keys
+/// in ordered key-value stores. Both cases sort correctly, but should not be
+/// mixed within the same keyspace.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Base32Hex(Yyid);
+
+/// Format a [`Yyid`] as a fixed-width [Base58](https://en.bitcoin.it/wiki/Base58Check_encoding)
+/// string with the Bitcoin alphabet, like `RH7tpZ2BoHoy1H9m8CLC47`.
+///
+/// The alphabet leaves out the easily confused `0`, `O`, `I` and `l`. Short
+/// values are padded with leading `1`s (the zero digit) to 22 characters.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Base58(Yyid);
+
+/// Format a [`Yyid`] as a fixed-width Base62 string of `[0-9A-Za-z]`, like
+/// `5yzY2MNOjZzhgrebe3Nnz6`.
+///
+/// The 22 characters are URL safe and sort in the same (byte-wise) order as
+/// the YYIDs themselves.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Base62(Yyid);
+
+/// Format a [`Yyid`] as an unpadded, URL-safe [Base64](https://www.rfc-editor.org/rfc/rfc4648#section-5)
+/// string, like `xJt59SLU3ELyFPQgnIDQSA`.
+///
+/// At 22 characters this is the most compact text format, well suited for
+/// JSON payloads. Unlike the other formats it does not sort like the YYIDs.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Base64Url(Yyid);
+
+/// Format a [`Yyid`] with a type prefix, like `usr_64kdwza8pmvh1f457m42e81m28`.
+///
+/// The prefix consists of 1 to 63 lower-case ASCII letters and is followed by
+/// `_` and the lower-case [`Base32`] encoding of the YYID, in the style of
+/// [TypeID](https://github.com/jetify-com/typeid). Seeing `org_` or `usr_` in a
+/// log line makes it obvious which kind of entity an ID refers to, and
+/// [`Prefixed::parse_with_prefix`] rejects IDs of the wrong kind.
+///
+/// ### Example
+/// ```rust
+/// use yyid::{fmts::Prefixed, Yyid};
+///
+/// let yyid = Yyid::parse_str("c49b79f5-22d4-dc42-f214-f4209c80d048").unwrap();
+/// let id = Prefixed::new("usr", yyid).unwrap();
+/// assert_eq!(id.to_string(), "usr_64kdwza8pmvh1f457m42e81m28");
+///
+/// assert_eq!(Prefixed::parse_with_prefix("usr_64kdwza8pmvh1f457m42e81m28", "usr"), Ok(yyid));
+/// assert!(Prefixed::parse_with_prefix("org_64kdwza8pmvh1f457m42e81m28", "usr").is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Prefixed<'a> {
+    prefix: &'a str,
+    yyid: Yyid,
+}
+
 #[inline]
 const fn format_simple(src: &[u8; 16], upper: bool) -> [u8; 32] {
     let lut = if upper { &UPPER } else { &LOWER };
@@ -81,6 +216,88 @@ const fn format_hyphenated(src: &[u8; 16], upper: bool) -> [u8; 36] {
     dst
 }
 
+#[inline]
+const fn format_base32(src: &[u8; 16], lut: &[u8; 32]) -> [u8; 26] {
+    let value = u128::from_be_bytes(*src);
+    let mut dst = [0; 26];
+    let mut i = 0;
+    while i < 26 {
+        // the first digit only holds the 3 most significant bits
+        dst[i] = lut[((value >> (5 * (25 - i))) & 0x1f) as usize];
+        i += 1;
+    }
+    dst
+}
+
+/// Builds a table mapping ASCII bytes to their value in `alphabet`, or to
+/// `0xff` if they are not part of it.
+const fn radix_table(alphabet: &[u8]) -> [u8; 256] {
+    let mut buf = [0xff; 256];
+    let mut i = 0;
+    while i < alphabet.len() {
+        buf[alphabet[i] as usize] = i as u8;
+        i += 1;
+    }
+    buf
+}
+
+/// Formats the 128-bit value as 22 digits in the base of `alphabet`, most
+/// significant first; enough for any base of at least 57.
+#[inline]
+const fn format_radix22(src: &[u8; 16], alphabet: &[u8]) -> [u8; 22] {
+    let base = alphabet.len() as u128;
+    let mut value = u128::from_be_bytes(*src);
+    let mut dst = [alphabet[0]; 22];
+    let mut i = 22;
+    while i > 0 {
+        i -= 1;
+        dst[i] = alphabet[(value % base) as usize];
+        value /= base;
+    }
+    dst
+}
+
+#[inline]
+const fn format_base64url(src: &[u8; 16]) -> [u8; 22] {
+    let mut dst = [0; 22];
+    format_base64url_bytes(src, &mut dst);
+    dst
+}
+
+/// Length of the unpadded Base64 encoding of `len` bytes.
+pub(crate) const fn base64url_len(len: usize) -> usize {
+    (len * 4).div_ceil(3)
+}
+
+/// Encodes `src` as unpadded, URL-safe Base64 into `dst`, which must be
+/// [`base64url_len`] bytes long.
+pub(crate) const fn format_base64url_bytes(src: &[u8], dst: &mut [u8]) {
+    let mut i = 0;
+    let mut j = 0;
+    while i + 3 <= src.len() {
+        let (b0, b1, b2) = (src[i], src[i + 1], src[i + 2]);
+        dst[j] = BASE64_URL[(b0 >> 2) as usize];
+        dst[j + 1] = BASE64_URL[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize];
+        dst[j + 2] = BASE64_URL[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize];
+        dst[j + 3] = BASE64_URL[(b2 & 0x3f) as usize];
+        i += 3;
+        j += 4;
+    }
+    // the remaining bytes are padded with zero bits to whole digits
+    match src.len() - i {
+        1 => {
+            dst[j] = BASE64_URL[(src[i] >> 2) as usize];
+            dst[j + 1] = BASE64_URL[((src[i] & 0x03) << 4) as usize];
+        }
+        2 => {
+            dst[j] = BASE64_URL[(src[i] >> 2) as usize];
+            dst[j + 1] = BASE64_URL[(((src[i] & 0x03) << 4) | (src[i + 1] >> 4)) as usize];
+            dst[j + 2] = BASE64_URL[((src[i + 1] & 0x0f) << 2) as usize];
+        }
+        _ => {}
+    }
+}
+
 #[inline]
 fn encode_simple<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut str {
     let buf = &mut buffer[..Simple::LENGTH];
@@ -138,6 +355,197 @@ fn encode_urn<'b>(src: &[u8; 16], buffer: &'b mut [u8], upper: bool) -> &'b mut
     }
 }
 
+#[inline]
+fn encode_base32<'b>(src: &[u8; 16], buffer: &'b mut [u8], lut: &[u8; 32]) -> &'b mut str {
+    let buf = &mut buffer[..Base32::LENGTH];
+    let dst = buf.as_mut_ptr();
+
+    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
+    // SAFETY: The encoded buffer is ASCII encoded
+    unsafe {
+        ptr::write(dst.cast(), format_base32(src, lut));
+        str::from_utf8_unchecked_mut(buf)
+    }
+}
+
+const fn parse_base32(s: &[u8], table: &[u8; 256], expected: &'static str) -> Result<Yyid, Error> {
+    if s.len() != Base32::LENGTH {
+        return Err(Error(ErrorKind::FormatLength {
+            expected: Base32::LENGTH,
+            len: s.len(),
+        }));
+    }
+
+    let mut value: u128 = 0;
+    let mut i = 0;
+    while i < Base32::LENGTH {
+        let digit = table[s[i] as usize];
+        if digit == 0xff {
+            return Err(Error(ErrorKind::Digit {
+                character: char_at(s, i),
+                index: i,
+                expected,
+            }));
+        }
+        // 26 digits hold 130 bits, so the first one must not exceed 3 bits
+        if i == 0 && digit > 0x07 {
+            return Err(Error(ErrorKind::Overflow));
+        }
+        value = (value << 5) | digit as u128;
+        i += 1;
+    }
+    Ok(Yyid(value.to_be_bytes()))
+}
+
+#[inline]
+fn encode_radix22<'b>(src: &[u8; 16], buffer: &'b mut [u8], alphabet: &[u8]) -> &'b mut str {
+    let buf = &mut buffer[..22];
+    let dst = buf.as_mut_ptr();
+
+    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
+    // SAFETY: The encoded buffer is ASCII encoded
+    unsafe {
+        ptr::write(dst.cast(), format_radix22(src, alphabet));
+        str::from_utf8_unchecked_mut(buf)
+    }
+}
+
+/// Parses 22 digits as encoded by [`format_radix22`], using the `table` built
+/// from the same alphabet.
+const fn parse_radix22(s: &[u8], table: &[u8; 256], base: u128, expected: &'static str) -> Result<Yyid, Error> {
+    if s.len() != 22 {
+        return Err(Error(ErrorKind::FormatLength {
+            expected: 22,
+            len: s.len(),
+        }));
+    }
+
+    let mut value: u128 = 0;
+    let mut i = 0;
+    while i < 22 {
+        let digit = table[s[i] as usize];
+        if digit == 0xff {
+            return Err(Error(ErrorKind::Digit {
+                character: char_at(s, i),
+                index: i,
+                expected,
+            }));
+        }
+        value = match value.checked_mul(base) {
+            Some(value) => match value.checked_add(digit as u128) {
+                Some(value) => value,
+                None => return Err(Error(ErrorKind::Overflow)),
+            },
+            None => return Err(Error(ErrorKind::Overflow)),
+        };
+        i += 1;
+    }
+    Ok(Yyid(value.to_be_bytes()))
+}
+
+#[inline]
+fn encode_base64url<'b>(src: &[u8; 16], buffer: &'b mut [u8]) -> &'b mut str {
+    let buf = &mut buffer[..Base64Url::LENGTH];
+    let dst = buf.as_mut_ptr();
+
+    // SAFETY: `buf` is guaranteed to be at least `LEN` bytes
+    // SAFETY: The encoded buffer is ASCII encoded
+    unsafe {
+        ptr::write(dst.cast(), format_base64url(src));
+        str::from_utf8_unchecked_mut(buf)
+    }
+}
+
+const fn parse_base64url(s: &[u8], lenient: bool) -> Result<Yyid, Error> {
+    let (table, expected) = if lenient {
+        (BASE64_ANY_TABLE, "[A-Za-z0-9-_+/]")
+    } else {
+        (BASE64_URL_TABLE, "[A-Za-z0-9-_]")
+    };
+
+    let s = match s {
+        [s @ .., b'=', b'='] if lenient && s.len() == Base64Url::LENGTH => s,
+        _ if s.len() == Base64Url::LENGTH => s,
+        _ => {
+            return Err(Error(ErrorKind::FormatLength {
+                expected: Base64Url::LENGTH,
+                len: s.len(),
+            }))
+        }
+    };
+
+    let mut bytes = [0; 16];
+    match parse_base64url_bytes(s, &mut bytes, table, expected) {
+        Ok(()) => Ok(Yyid(bytes)),
+        Err(err) => Err(err),
+    }
+}
+
+/// Decodes unpadded Base64 digits from `table` into `dst`, where `s` must be
+/// [`base64url_len`] of `dst` bytes long. The padding bits of the last digit
+/// must be zero, so every value has exactly one encoding.
+pub(crate) const fn parse_base64url_bytes(
+    s: &[u8],
+    dst: &mut [u8],
+    table: &[u8; 256],
+    expected: &'static str,
+) -> Result<(), Error> {
+    let mut acc: u16 = 0;
+    let mut bits = 0;
+    let mut i = 0;
+    let mut j = 0;
+    while i < s.len() {
+        let digit = table[s[i] as usize];
+        if digit == 0xff {
+            return Err(Error(ErrorKind::Digit {
+                character: char_at(s, i),
+                index: i,
+                expected,
+            }));
+        }
+        acc = (acc << 6) | digit as u16;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            dst[j] = (acc >> bits) as u8;
+            acc &= (1 << bits) - 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    if acc != 0 {
+        return Err(Error(ErrorKind::Digit {
+            character: s[s.len() - 1] as char,
+            index: s.len() - 1,
+            expected: if bits == 4 {
+                "one of `A`, `Q`, `g` or `w`"
+            } else {
+                "one of [AEIMQUYcgkosw048]"
+            },
+        }));
+    }
+    Ok(())
+}
+
+const fn validate_prefix(prefix: &[u8]) -> Result<(), Error> {
+    if prefix.is_empty() || prefix.len() > Prefixed::MAX_PREFIX_LENGTH {
+        return Err(Error(ErrorKind::PrefixLength { len: prefix.len() }));
+    }
+
+    let mut i = 0;
+    while i < prefix.len() {
+        if !prefix[i].is_ascii_lowercase() {
+            return Err(Error(ErrorKind::Digit {
+                character: char_at(prefix, i),
+                index: i,
+                expected: "[a-z]",
+            }));
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
 // === impls ===
 
 impl Yyid {
@@ -192,6 +600,71 @@ impl Yyid {
         // SAFETY: `Yyid` and `Braced` have the same ABI
         unsafe { &*(self as *const Yyid as *const Braced) }
     }
+
+    /// Get an owned [`Base32`] from a [`Yyid`]
+    #[inline]
+    pub const fn base32(self) -> Base32 {
+        Base32(self)
+    }
+
+    /// Get a borrowed [`Base32`] from a [`Yyid`]
+    #[inline]
+    pub const fn as_base32(&self) -> &Base32 {
+        // SAFETY: `Yyid` and `Base32` have the same ABI
+        unsafe { &*(self as *const Yyid as *const Base32) }
+    }
+
+    /// Get an owned [`Base32Hex`] from a [`Yyid`]
+    #[inline]
+    pub const fn base32hex(self) -> Base32Hex {
+        Base32Hex(self)
+    }
+
+    /// Get a borrowed [`Base32Hex`] from a [`Yyid`]
+    #[inline]
+    pub const fn as_base32hex(&self) -> &Base32Hex {
+        // SAFETY: `Yyid` and `Base32Hex` have the same ABI
+        unsafe { &*(self as *const Yyid as *const Base32Hex) }
+    }
+
+    /// Get an owned [`Base58`] from a [`Yyid`]
+    #[inline]
+    pub const fn base58(self) -> Base58 {
+        Base58(self)
+    }
+
+    /// Get a borrowed [`Base58`] from a [`Yyid`]
+    #[inline]
+    pub const fn as_base58(&self) -> &Base58 {
+        // SAFETY: `Yyid` and `Base58` have the same ABI
+        unsafe { &*(self as *const Yyid as *const Base58) }
+    }
+
+    /// Get an owned [`Base62`] from a [`Yyid`]
+    #[inline]
+    pub const fn base62(self) -> Base62 {
+        Base62(self)
+    }
+
+    /// Get a borrowed [`Base62`] from a [`Yyid`]
+    #[inline]
+    pub const fn as_base62(&self) -> &Base62 {
+        // SAFETY: `Yyid` and `Base62` have the same ABI
+        unsafe { &*(self as *const Yyid as *const Base62) }
+    }
+
+    /// Get an owned [`Base64Url`] from a [`Yyid`]
+    #[inline]
+    pub const fn base64url(self) -> Base64Url {
+        Base64Url(self)
+    }
+
+    /// Get a borrowed [`Base64Url`] from a [`Yyid`]
+    #[inline]
+    pub const fn as_base64url(&self) -> &Base64Url {
+        // SAFETY: `Yyid` and `Base64Url` have the same ABI
+        unsafe { &*(self as *const Yyid as *const Base64Url) }
+    }
 }
 
 impl Hyphenated {
@@ -338,6 +811,346 @@ impl Braced {
     }
 }
 
+impl Base32 {
+    /// Base32 string length
+    pub const LENGTH: usize = 26;
+
+    /// Wraps a [`Yyid`] into a [`Base32`]
+    pub const fn from_yyid(yyid: Yyid) -> Self {
+        Self(yyid)
+    }
+
+    /// Get a reference to the underlying [`Yyid`].
+    pub const fn as_yyid(&self) -> &Yyid {
+        &self.0
+    }
+
+    /// Consumes the [`Base32`], returning the underlying [`Yyid`].
+    pub const fn into_yyid(self) -> Yyid {
+        self.0
+    }
+
+    /// Writes the [`Yyid`] as a lower-case Base32 string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded YYID.
+    #[inline]
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base32(self.0.as_bytes(), buffer, BASE32_LOWER)
+    }
+
+    /// Writes the [`Yyid`] as a upper-case Base32 string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded YYID.
+    #[inline]
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base32(self.0.as_bytes(), buffer, BASE32_UPPER)
+    }
+
+    /// Parses a [`Yyid`] from a Base32 string.
+    ///
+    /// Decoding ignores case and accepts the commonly confused `I` and `L`
+    /// for `1` and `O` for `0`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::{fmts::Base32, Yyid};
+    ///
+    /// let yyid = Base32::parse_str("64KDWZA8PMVH1F457M42E81M28").unwrap();
+    /// assert_eq!(yyid.to_string(), "c49b79f5-22d4-dc42-f214-f4209c80d048");
+    /// assert_eq!(Base32::parse_str("64kdwza8pmvhif457m42e8lm28"), Ok(yyid));
+    /// ```
+    pub const fn parse_str(input: &str) -> Result<Yyid, Error> {
+        parse_base32(input.as_bytes(), BASE32_TABLE, "[0-9A-Za-z] without U")
+    }
+}
+
+impl Base32Hex {
+    /// Base32Hex string length
+    pub const LENGTH: usize = 26;
+
+    /// Wraps a [`Yyid`] into a [`Base32Hex`]
+    pub const fn from_yyid(yyid: Yyid) -> Self {
+        Self(yyid)
+    }
+
+    /// Get a reference to the underlying [`Yyid`].
+    pub const fn as_yyid(&self) -> &Yyid {
+        &self.0
+    }
+
+    /// Consumes the [`Base32Hex`], returning the underlying [`Yyid`].
+    pub const fn into_yyid(self) -> Yyid {
+        self.0
+    }
+
+    /// Writes the [`Yyid`] as a lower-case Base32Hex string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded YYID.
+    #[inline]
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base32(self.0.as_bytes(), buffer, BASE32HEX_LOWER)
+    }
+
+    /// Writes the [`Yyid`] as a upper-case Base32Hex string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded YYID.
+    #[inline]
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base32(self.0.as_bytes(), buffer, BASE32HEX_UPPER)
+    }
+
+    /// Parses a [`Yyid`] from a Base32Hex string, ignoring case.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::{fmts::Base32Hex, Yyid};
+    ///
+    /// let yyid = Base32Hex::parse_str("64JDSVA8MKRH1F457K42E81K28").unwrap();
+    /// assert_eq!(yyid.to_string(), "c49b79f5-22d4-dc42-f214-f4209c80d048");
+    /// assert_eq!(Base32Hex::parse_str("64jdsva8mkrh1f457k42e81k28"), Ok(yyid));
+    /// ```
+    pub const fn parse_str(input: &str) -> Result<Yyid, Error> {
+        parse_base32(input.as_bytes(), BASE32HEX_TABLE, "[0-9A-Va-v]")
+    }
+}
+
+impl Base58 {
+    /// Base58 string length
+    pub const LENGTH: usize = 22;
+
+    /// Wraps a [`Yyid`] into a [`Base58`]
+    pub const fn from_yyid(yyid: Yyid) -> Self {
+        Self(yyid)
+    }
+
+    /// Get a reference to the underlying [`Yyid`].
+    pub const fn as_yyid(&self) -> &Yyid {
+        &self.0
+    }
+
+    /// Consumes the [`Base58`], returning the underlying [`Yyid`].
+    pub const fn into_yyid(self) -> Yyid {
+        self.0
+    }
+
+    /// Writes the [`Yyid`] as a Base58 string to `buffer`, and returns the
+    /// subslice of the buffer that contains the encoded YYID.
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_radix22(self.0.as_bytes(), buffer, BASE58)
+    }
+
+    /// Parses a [`Yyid`] from a Base58 string of exactly 22 characters.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::{fmts::Base58, Yyid};
+    ///
+    /// let yyid = Base58::parse_str("RH7tpZ2BoHoy1H9m8CLC47").unwrap();
+    /// assert_eq!(yyid.to_string(), "c49b79f5-22d4-dc42-f214-f4209c80d048");
+    /// assert!(Base58::parse_str("RH7tpZ2BoHoy1H9m8CLC4O").is_err());
+    /// ```
+    pub const fn parse_str(input: &str) -> Result<Yyid, Error> {
+        parse_radix22(input.as_bytes(), BASE58_TABLE, 58, "[1-9A-Za-z] without O, I and l")
+    }
+}
+
+impl Base62 {
+    /// Base62 string length
+    pub const LENGTH: usize = 22;
+
+    /// Wraps a [`Yyid`] into a [`Base62`]
+    pub const fn from_yyid(yyid: Yyid) -> Self {
+        Self(yyid)
+    }
+
+    /// Get a reference to the underlying [`Yyid`].
+    pub const fn as_yyid(&self) -> &Yyid {
+        &self.0
+    }
+
+    /// Consumes the [`Base62`], returning the underlying [`Yyid`].
+    pub const fn into_yyid(self) -> Yyid {
+        self.0
+    }
+
+    /// Writes the [`Yyid`] as a Base62 string to `buffer`, and returns the
+    /// subslice of the buffer that contains the encoded YYID.
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_radix22(self.0.as_bytes(), buffer, BASE62)
+    }
+
+    /// Parses a [`Yyid`] from a Base62 string of exactly 22 characters.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::{fmts::Base62, Yyid};
+    ///
+    /// let yyid = Base62::parse_str("5yzY2MNOjZzhgrebe3Nnz6").unwrap();
+    /// assert_eq!(yyid.to_string(), "c49b79f5-22d4-dc42-f214-f4209c80d048");
+    /// ```
+    pub const fn parse_str(input: &str) -> Result<Yyid, Error> {
+        parse_radix22(input.as_bytes(), BASE62_TABLE, 62, "[0-9A-Za-z]")
+    }
+}
+
+impl Base64Url {
+    /// Base64Url string length
+    pub const LENGTH: usize = base64url_len(16);
+
+    /// Wraps a [`Yyid`] into a [`Base64Url`]
+    pub const fn from_yyid(yyid: Yyid) -> Self {
+        Self(yyid)
+    }
+
+    /// Get a reference to the underlying [`Yyid`].
+    pub const fn as_yyid(&self) -> &Yyid {
+        &self.0
+    }
+
+    /// Consumes the [`Base64Url`], returning the underlying [`Yyid`].
+    pub const fn into_yyid(self) -> Yyid {
+        self.0
+    }
+
+    /// Writes the [`Yyid`] as an unpadded, URL-safe Base64 string to
+    /// `buffer`, and returns the subslice of the buffer that contains the
+    /// encoded YYID.
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        encode_base64url(self.0.as_bytes(), buffer)
+    }
+
+    /// Parses a [`Yyid`] from exactly the 22 characters produced by
+    /// [`Base64Url::encode`].
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::{fmts::Base64Url, Yyid};
+    ///
+    /// let yyid = Base64Url::parse_str("xJt59SLU3ELyFPQgnIDQSA").unwrap();
+    /// assert_eq!(yyid.to_string(), "c49b79f5-22d4-dc42-f214-f4209c80d048");
+    /// assert!(Base64Url::parse_str("xJt59SLU3ELyFPQgnIDQSA==").is_err());
+    /// ```
+    pub const fn parse_str(input: &str) -> Result<Yyid, Error> {
+        parse_base64url(input.as_bytes(), false)
+    }
+
+    /// Parses a [`Yyid`] from Base64, additionally accepting `==` padding and
+    /// the standard alphabet (`+` and `/` instead of `-` and `_`).
+    ///
+    /// Bits beyond the 128 bits of the YYID must still be zero.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::{fmts::Base64Url, Yyid};
+    ///
+    /// let yyid = Base64Url::parse_str_lenient("/////////////////////w==").unwrap();
+    /// assert_eq!(yyid.to_string(), "ffffffff-ffff-ffff-ffff-ffffffffffff");
+    /// ```
+    pub const fn parse_str_lenient(input: &str) -> Result<Yyid, Error> {
+        parse_base64url(input.as_bytes(), true)
+    }
+}
+
+impl<'a> Prefixed<'a> {
+    /// Maximum length of the prefix
+    pub const MAX_PREFIX_LENGTH: usize = 63;
+
+    /// Maximum length of the encoded string, with the longest prefix
+    pub const MAX_LENGTH: usize = Self::MAX_PREFIX_LENGTH + 1 + Base32::LENGTH;
+
+    /// Combines a prefix and a [`Yyid`] into a [`Prefixed`], after checking
+    /// that the prefix consists of 1 to 63 lower-case ASCII letters.
+    pub const fn new(prefix: &'a str, yyid: Yyid) -> Result<Self, Error> {
+        match validate_prefix(prefix.as_bytes()) {
+            Ok(()) => Ok(Self { prefix, yyid }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Get the prefix.
+    pub const fn prefix(&self) -> &'a str {
+        self.prefix
+    }
+
+    /// Get a reference to the underlying [`Yyid`].
+    pub const fn as_yyid(&self) -> &Yyid {
+        &self.yyid
+    }
+
+    /// Consumes the [`Prefixed`], returning the underlying [`Yyid`].
+    pub const fn into_yyid(self) -> Yyid {
+        self.yyid
+    }
+
+    /// Writes the prefix, `_` and the lower-case Base32 YYID to `buffer`,
+    /// and returns the subslice of the buffer that contains the encoded YYID.
+    ///
+    /// A buffer of [`Prefixed::MAX_LENGTH`] bytes fits any prefix.
+    #[inline]
+    pub fn encode<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let len = self.prefix.len() + 1 + Base32::LENGTH;
+        let buf = &mut buffer[..len];
+        let (prefix, rest) = buf.split_at_mut(self.prefix.len());
+        prefix.copy_from_slice(self.prefix.as_bytes());
+        rest[0] = b'_';
+        rest[1..].copy_from_slice(&format_base32(self.yyid.as_bytes(), BASE32_LOWER));
+
+        // SAFETY: The prefix and the encoded YYID are ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Parses a prefix and a [`Yyid`] from a prefixed string.
+    ///
+    /// The part after the last `_` must be lower-case Base32. Unlike
+    /// [`Base32::parse_str`], upper case and the substitutes for `1` and `0`
+    /// are rejected, so every ID has exactly one spelling.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::{fmts::Prefixed, Yyid};
+    ///
+    /// let id = Prefixed::parse_str("org_64kdwza8pmvh1f457m42e81m28").unwrap();
+    /// assert_eq!(id.prefix(), "org");
+    /// assert_eq!(id.as_yyid().to_string(), "c49b79f5-22d4-dc42-f214-f4209c80d048");
+    /// ```
+    pub fn parse_str(input: &'a str) -> Result<Self, Error> {
+        let Some((prefix, encoded)) = input.rsplit_once('_') else {
+            return Err(Error(ErrorKind::Separator));
+        };
+        validate_prefix(prefix.as_bytes())?;
+
+        let yyid = parse_base32(encoded.as_bytes(), BASE32_LOWER_TABLE, "[0-9a-z] without i, l, o and u").map_err(
+            |Error(kind)| match kind {
+                // report the index within the whole input
+                ErrorKind::Digit {
+                    character,
+                    index,
+                    expected,
+                } => Error(ErrorKind::Digit {
+                    character,
+                    index: prefix.len() + 1 + index,
+                    expected,
+                }),
+                kind => Error(kind),
+            },
+        )?;
+        Ok(Self { prefix, yyid })
+    }
+
+    /// Parses a [`Yyid`] from a prefixed string, failing unless its prefix
+    /// is `expected`.
+    pub fn parse_with_prefix(input: &str, expected: &str) -> Result<Yyid, Error> {
+        let prefixed = Prefixed::parse_str(input)?;
+        if prefixed.prefix != expected {
+            return Err(Error(ErrorKind::PrefixMismatch));
+        }
+        Ok(prefixed.yyid)
+    }
+}
+
 // === Formatters ===
 
 macro_rules! impl_fmt_traits {
@@ -432,3 +1245,385 @@ impl_fmt_traits! {
     Urn<>,
     Braced<>
 }
+
+macro_rules! impl_fmt_display {
+    ($($T:ident),+) => {$(
+        impl_fmt_display! { $T => encode }
+    )+};
+    ($($T:ident => $encode:ident),+) => {$(
+        impl fmt::Display for $T {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.$encode(&mut [0; Self::LENGTH]))
+            }
+        }
+
+        impl_fmt_from! { $T<> }
+    )+}
+}
+
+impl fmt::Display for Prefixed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode(&mut [0; Self::MAX_LENGTH]))
+    }
+}
+
+impl From<Prefixed<'_>> for Yyid {
+    #[inline]
+    fn from(f: Prefixed<'_>) -> Self {
+        f.into_yyid()
+    }
+}
+
+impl AsRef<Yyid> for Prefixed<'_> {
+    #[inline]
+    fn as_ref(&self) -> &Yyid {
+        &self.yyid
+    }
+}
+
+impl Borrow<Yyid> for Prefixed<'_> {
+    #[inline]
+    fn borrow(&self) -> &Yyid {
+        &self.yyid
+    }
+}
+
+impl_fmt_display! {
+    Base32 => encode_lower,
+    Base32Hex => encode_lower
+}
+
+impl_fmt_display! {
+    Base58,
+    Base62,
+    Base64Url
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fmts::*,
+        std::{
+            format,
+            string::{String, ToString},
+            vec::Vec,
+        },
+    };
+    use pretty_assertions::assert_eq;
+
+    const YYID: Yyid = crate::yyid!("c49b79f5-22d4-dc42-f214-f4209c80d048");
+    const MAX: Yyid = crate::yyid!("ffffffff-ffff-ffff-ffff-ffffffffffff");
+
+    /// Checks that `parse` recovers random YYIDs from their `encode`d form.
+    fn assert_round_trip(encode: impl Fn(&Yyid) -> String, parse: impl Fn(&str) -> Result<Yyid, Error>) {
+        for _ in 0..100 {
+            let yyid = Yyid::new();
+            assert_eq!(parse(&encode(&yyid)), Ok(yyid));
+        }
+    }
+
+    #[test]
+    fn test_base32() {
+        assert_eq!(YYID.as_base32().to_string(), "64kdwza8pmvh1f457m42e81m28");
+        assert_eq!(
+            YYID.as_base32().encode_upper(&mut [0; Base32::LENGTH]),
+            "64KDWZA8PMVH1F457M42E81M28"
+        );
+        assert_eq!(Yyid::nil().base32().to_string(), "00000000000000000000000000");
+        assert_eq!(MAX.as_base32().to_string(), "7zzzzzzzzzzzzzzzzzzzzzzzzz");
+    }
+
+    #[test]
+    fn test_base32_round_trip() {
+        assert_round_trip(|yyid| yyid.as_base32().to_string(), Base32::parse_str);
+        assert_round_trip(
+            |yyid| yyid.as_base32().encode_upper(&mut [0; Base32::LENGTH]).to_string(),
+            Base32::parse_str,
+        );
+    }
+
+    #[test]
+    fn test_base32_parse_errors() {
+        let err = |s| Base32::parse_str(s).unwrap_err().kind();
+
+        assert_eq!(
+            err("64kdwza8pmvh1f457m42e81m2"),
+            ErrorKind::FormatLength { expected: 26, len: 25 }
+        );
+        assert_eq!(
+            err("64kdwza8pmvh1f457m42e81mu8"),
+            ErrorKind::Digit {
+                character: 'u',
+                index: 24,
+                expected: "[0-9A-Za-z] without U"
+            }
+        );
+        assert_eq!(err("80000000000000000000000000"), ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn test_base32hex() {
+        assert_eq!(YYID.as_base32hex().to_string(), "64jdsva8mkrh1f457k42e81k28");
+        assert_eq!(
+            YYID.as_base32hex().encode_upper(&mut [0; Base32Hex::LENGTH]),
+            "64JDSVA8MKRH1F457K42E81K28"
+        );
+        assert_eq!(Yyid::nil().base32hex().to_string(), "00000000000000000000000000");
+        assert_eq!(MAX.as_base32hex().to_string(), "7vvvvvvvvvvvvvvvvvvvvvvvvv");
+
+        assert_round_trip(|yyid| yyid.as_base32hex().to_string(), Base32Hex::parse_str);
+        assert_round_trip(
+            |yyid| {
+                yyid.as_base32hex()
+                    .encode_upper(&mut [0; Base32Hex::LENGTH])
+                    .to_string()
+            },
+            Base32Hex::parse_str,
+        );
+
+        let err = |s| Base32Hex::parse_str(s).unwrap_err().kind();
+        assert_eq!(
+            err("64jdsva8mkrh1f457k42e81k2"),
+            ErrorKind::FormatLength { expected: 26, len: 25 }
+        );
+        assert_eq!(
+            err("64jdsva8mkrh1f457k42e81kw8"),
+            ErrorKind::Digit {
+                character: 'w',
+                index: 24,
+                expected: "[0-9A-Va-v]"
+            }
+        );
+        assert_eq!(err("8vvvvvvvvvvvvvvvvvvvvvvvvv"), ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn test_base58() {
+        assert_eq!(YYID.as_base58().to_string(), "RH7tpZ2BoHoy1H9m8CLC47");
+        assert_eq!(Yyid::nil().base58().to_string(), "1111111111111111111111");
+        assert_eq!(MAX.as_base58().to_string(), "YcVfxkQb6JRzqk5kF2tNLv");
+        assert_eq!(Base58::parse_str("YcVfxkQb6JRzqk5kF2tNLv"), Ok(MAX));
+
+        assert_round_trip(|yyid| yyid.as_base58().to_string(), Base58::parse_str);
+    }
+
+    #[test]
+    fn test_base58_parse_errors() {
+        let err = |s| Base58::parse_str(s).unwrap_err().kind();
+
+        assert_eq!(
+            err("RH7tpZ2BoHoy1H9m8CLC4"),
+            ErrorKind::FormatLength { expected: 22, len: 21 }
+        );
+        assert_eq!(
+            err("RH7tpZ2BoHoy1H9m8CLC0x"),
+            ErrorKind::Digit {
+                character: '0',
+                index: 20,
+                expected: "[1-9A-Za-z] without O, I and l"
+            }
+        );
+        assert_eq!(err("YcVfxkQb6JRzqk5kF2tNLw"), ErrorKind::Overflow);
+        assert_eq!(err("zzzzzzzzzzzzzzzzzzzzzz"), ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn test_base62() {
+        assert_eq!(YYID.as_base62().to_string(), "5yzY2MNOjZzhgrebe3Nnz6");
+        assert_eq!(Yyid::nil().base62().to_string(), "0000000000000000000000");
+        assert_eq!(MAX.as_base62().to_string(), "7n42DGM5Tflk9n8mt7Fhc7");
+
+        assert_round_trip(|yyid| yyid.as_base62().to_string(), Base62::parse_str);
+
+        let err = |s| Base62::parse_str(s).unwrap_err().kind();
+        assert_eq!(
+            err("5yzY2MNOjZzhgrebe3Nnz"),
+            ErrorKind::FormatLength { expected: 22, len: 21 }
+        );
+        assert_eq!(
+            err("5yzY2MNOjZzh-rebe3Nnz6"),
+            ErrorKind::Digit {
+                character: '-',
+                index: 12,
+                expected: "[0-9A-Za-z]"
+            }
+        );
+        assert_eq!(err("7n42DGM5Tflk9n8mt7Fhc8"), ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn test_base64url() {
+        assert_eq!(YYID.as_base64url().to_string(), "xJt59SLU3ELyFPQgnIDQSA");
+        assert_eq!(Yyid::nil().base64url().to_string(), "AAAAAAAAAAAAAAAAAAAAAA");
+        assert_eq!(MAX.as_base64url().to_string(), "_____________________w");
+
+        assert_round_trip(|yyid| yyid.as_base64url().to_string(), Base64Url::parse_str);
+        assert_round_trip(|yyid| yyid.as_base64url().to_string(), Base64Url::parse_str_lenient);
+        assert_round_trip(
+            |yyid| format!("{}==", yyid.as_base64url()),
+            Base64Url::parse_str_lenient,
+        );
+    }
+
+    #[test]
+    fn test_base64url_strict_and_lenient() {
+        assert_eq!(Base64Url::parse_str_lenient("/////////////////////w"), Ok(MAX));
+        assert_eq!(Base64Url::parse_str_lenient("_____________________w=="), Ok(MAX));
+        assert_eq!(
+            Base64Url::parse_str("/////////////////////w").unwrap_err().kind(),
+            ErrorKind::Digit {
+                character: '/',
+                index: 0,
+                expected: "[A-Za-z0-9-_]"
+            }
+        );
+        assert_eq!(
+            Base64Url::parse_str("_____________________w==").unwrap_err().kind(),
+            ErrorKind::FormatLength { expected: 22, len: 24 }
+        );
+        assert_eq!(
+            Base64Url::parse_str_lenient("_____________________w=")
+                .unwrap_err()
+                .kind(),
+            ErrorKind::FormatLength { expected: 22, len: 23 }
+        );
+    }
+
+    #[test]
+    fn test_base64url_non_canonical() {
+        for parse in [Base64Url::parse_str, Base64Url::parse_str_lenient] {
+            assert_eq!(
+                parse("_____________________x").unwrap_err().kind(),
+                ErrorKind::Digit {
+                    character: 'x',
+                    index: 21,
+                    expected: "one of `A`, `Q`, `g` or `w`"
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_prefixed() {
+        let id = Prefixed::new("usr", YYID).unwrap();
+        assert_eq!(id.prefix(), "usr");
+        assert_eq!(id.to_string(), "usr_64kdwza8pmvh1f457m42e81m28");
+        assert_eq!(Prefixed::parse_str("usr_64kdwza8pmvh1f457m42e81m28"), Ok(id));
+
+        let longest = "a".repeat(Prefixed::MAX_PREFIX_LENGTH);
+        let id = Prefixed::new(&longest, Yyid::nil()).unwrap();
+        assert_eq!(id.to_string().len(), Prefixed::MAX_LENGTH);
+
+        for _ in 0..100 {
+            let yyid = Yyid::new();
+            let encoded = Prefixed::new("org", yyid).unwrap().to_string();
+
+            assert_eq!(Prefixed::parse_with_prefix(&encoded, "org"), Ok(yyid));
+            assert_eq!(
+                Prefixed::parse_with_prefix(&encoded, "usr").unwrap_err().kind(),
+                ErrorKind::PrefixMismatch
+            );
+        }
+    }
+
+    #[test]
+    fn test_prefixed_errors() {
+        let new = |p: &str| Prefixed::new(p, YYID).unwrap_err().kind();
+        let err = |s| Prefixed::parse_str(s).unwrap_err().kind();
+
+        assert_eq!(new(""), ErrorKind::PrefixLength { len: 0 });
+        assert_eq!(
+            new(&"a".repeat(Prefixed::MAX_PREFIX_LENGTH + 1)),
+            ErrorKind::PrefixLength { len: 64 }
+        );
+        assert_eq!(
+            new("Usr"),
+            ErrorKind::Digit {
+                character: 'U',
+                index: 0,
+                expected: "[a-z]"
+            }
+        );
+
+        assert_eq!(err("64kdwza8pmvh1f457m42e81m28"), ErrorKind::Separator);
+        assert_eq!(err("_64kdwza8pmvh1f457m42e81m28"), ErrorKind::PrefixLength { len: 0 });
+        assert_eq!(
+            err("usr_org_64kdwza8pmvh1f457m42e81m28"),
+            ErrorKind::Digit {
+                character: '_',
+                index: 3,
+                expected: "[a-z]"
+            }
+        );
+        assert_eq!(
+            err("usr_64kdwza8pmvh1f457m42e81mu8"),
+            ErrorKind::Digit {
+                character: 'u',
+                index: 28,
+                expected: "[0-9a-z] without i, l, o and u"
+            }
+        );
+        // only the canonical lower-case spelling is accepted
+        assert_eq!(
+            err("usr_64KDWZA8PMVH1F457M42E81M28"),
+            ErrorKind::Digit {
+                character: 'K',
+                index: 6,
+                expected: "[0-9a-z] without i, l, o and u"
+            }
+        );
+        assert_eq!(
+            err("usr_64kdwza8pmvhif457m42e8lm28"),
+            ErrorKind::Digit {
+                character: 'i',
+                index: 16,
+                expected: "[0-9a-z] without i, l, o and u"
+            }
+        );
+        assert_eq!(
+            err("usr_o0000000000000000000000000"),
+            ErrorKind::Digit {
+                character: 'o',
+                index: 4,
+                expected: "[0-9a-z] without i, l, o and u"
+            }
+        );
+        assert_eq!(
+            err("usr_64kdwza8pmvh1f457m42e81m2"),
+            ErrorKind::FormatLength { expected: 26, len: 25 }
+        );
+    }
+
+    /// Every order-preserving format must satisfy `a < b` iff `encode(a) < encode(b)`.
+    #[test]
+    fn test_sort_order() {
+        let mut yyids: Vec<Yyid> = (0..200).map(|_| Yyid::new()).collect();
+        yyids.extend([Yyid::nil(), MAX]);
+        // neighbours differing only in the least or most significant bits
+        yyids.extend((0..64u128).map(|n| Yyid((0x0123_4567_89ab_cdef_u128 * 64 + n).to_be_bytes())));
+        yyids.extend((0..8u128).map(|n| Yyid((n << 125).to_be_bytes())));
+
+        type Encoder = fn(&Yyid) -> String;
+        let encoders: [(&str, Encoder); 8] = [
+            ("hyphenated", |y| y.as_hyphenated().to_string()),
+            ("simple", |y| y.as_simple().to_string()),
+            ("base32", |y| y.as_base32().to_string()),
+            ("base32hex", |y| y.as_base32hex().to_string()),
+            ("base32hex upper", |y| {
+                y.as_base32hex().encode_upper(&mut [0; Base32Hex::LENGTH]).to_string()
+            }),
+            ("base58", |y| y.as_base58().to_string()),
+            ("base62", |y| y.as_base62().to_string()),
+            ("simple upper", |y| format!("{:X}", y.as_simple())),
+        ];
+
+        for (name, encode) in encoders {
+            let encoded: Vec<String> = yyids.iter().map(encode).collect();
+            for (a, ea) in yyids.iter().zip(&encoded) {
+                for (b, eb) in yyids.iter().zip(&encoded) {
+                    assert_eq!(a < b, ea < eb, "{name}: {a} vs {b}");
+                }
+            }
+        }
+    }
+}
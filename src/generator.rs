@@ -0,0 +1,123 @@
+//! A common interface for all sources of YYIDs
+//!
+//! Code that creates YYIDs can take a [`YyidGenerator`] instead of calling
+//! [`Yyid::new`] directly, so tests can swap in a deterministic source:
+//!
+//! ```rust
+//! use yyid::{generator::DefaultGenerator, Yyid, YyidGenerator};
+//!
+//! struct UserService<G> {
+//!     ids: G,
+//! }
+//!
+//! impl<G: YyidGenerator> UserService<G> {
+//!     fn create_user(&mut self) -> Yyid {
+//!         self.ids.generate()
+//!     }
+//! }
+//!
+//! let mut service = UserService { ids: DefaultGenerator };
+//! println!("{}", service.create_user());
+//! ```
+//!
+//! Implementations are provided for
+//!
+//! - [`DefaultGenerator`]: the same source as [`Yyid::new`]
+//! - [`OsGenerator`]: the operating system, via `getrandom`
+//! - [`BufferedGenerator`](crate::buffered::BufferedGenerator)
+//! - `rng::RngGenerator` (feature `rand`): any `rand` random number generator
+//! - `seeded::SeededGenerator` (feature `seeded`): a reproducible stream
+
+use crate::{bytes_of_mut, Error, Yyid};
+
+/// A source of YYIDs.
+pub trait YyidGenerator {
+    /// Returns the next YYID, or an error if the source failed.
+    ///
+    /// Implementations outside this crate report their failures with
+    /// [`Error::generator`].
+    fn try_generate(&mut self) -> Result<Yyid, Error>;
+
+    /// Returns the next YYID.
+    ///
+    /// ### Panics
+    /// Panics if the source failed; use [`YyidGenerator::try_generate`] to
+    /// handle this case.
+    fn generate(&mut self) -> Yyid {
+        self.try_generate()
+            .unwrap_or_else(|err| panic!("could not create a new YYID: {}", err))
+    }
+}
+
+impl<G: YyidGenerator + ?Sized> YyidGenerator for &mut G {
+    #[inline]
+    fn try_generate(&mut self) -> Result<Yyid, Error> {
+        (**self).try_generate()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<G: YyidGenerator + ?Sized> YyidGenerator for std::boxed::Box<G> {
+    #[inline]
+    fn try_generate(&mut self) -> Result<Yyid, Error> {
+        (**self).try_generate()
+    }
+}
+
+/// Generates YYIDs exactly like [`Yyid::new`], honouring the crate features.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultGenerator;
+
+impl YyidGenerator for DefaultGenerator {
+    #[inline]
+    fn try_generate(&mut self) -> Result<Yyid, Error> {
+        Yyid::try_new()
+    }
+}
+
+/// Generates YYIDs from the operating system's source of randomness,
+/// regardless of the crate features.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsGenerator;
+
+impl YyidGenerator for OsGenerator {
+    fn try_generate(&mut self) -> Result<Yyid, Error> {
+        let mut yyid = Yyid::nil();
+        getrandom::fill(bytes_of_mut(crate::std::slice::from_mut(&mut yyid))).map_err(Error::entropy)?;
+        Ok(yyid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{buffered::BufferedGenerator, generator::*};
+    use pretty_assertions::assert_ne;
+
+    fn two<G: YyidGenerator>(mut generator: G) -> (Yyid, Yyid) {
+        (generator.generate(), generator.try_generate().unwrap())
+    }
+
+    #[test]
+    fn test_generators() {
+        let (a, b) = two(DefaultGenerator);
+        assert_ne!(a, b);
+
+        let (a, b) = two(OsGenerator);
+        assert_ne!(a, b);
+
+        let (a, b) = two(BufferedGenerator::new());
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_dyn_generator() {
+        let mut buffered = BufferedGenerator::new();
+        let generators: [&mut dyn YyidGenerator; 3] = [&mut DefaultGenerator, &mut OsGenerator, &mut buffered];
+
+        for generator in generators {
+            let (a, b) = two(generator);
+            assert!(!a.is_nil());
+            assert_ne!(a, b);
+        }
+    }
+}
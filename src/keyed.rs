@@ -0,0 +1,76 @@
+//! Keyed derivation of YYIDs
+//!
+//! Like name-based YYIDs (`Yyid::from_name`), but using HMAC-SHA256
+//! with a secret key, so outsiders cannot derive or test for them.
+
+use crate::{Yyid, ZEROES};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+impl Yyid {
+    /// Derives a YYID from `input` with a secret `key`.
+    ///
+    /// The `context` separates different uses of the same key (e.g.
+    /// `b"user-email"` and `b"org-domain"`), so equal inputs in different
+    /// contexts never result in the same YYID.
+    ///
+    /// The YYID consists of the first 16 bytes of HMAC-SHA256 over the length
+    /// of `context` (as a big-endian `u64`), `context` and `input`. Without
+    /// the key it is indistinguishable from a random YYID, so deterministic
+    /// IDs for e.g. email addresses cannot be brute-forced by outsiders.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::Yyid;
+    ///
+    /// let key = [7u8; 32]; // changing the key changes every derived YYID
+    ///
+    /// let user = Yyid::derive_keyed(&key, b"user-email", b"alice@example.com");
+    /// assert_eq!(user, Yyid::derive_keyed(&key, b"user-email", b"alice@example.com"));
+    /// assert_ne!(user, Yyid::derive_keyed(&key, b"org-domain", b"alice@example.com"));
+    /// ```
+    pub fn derive_keyed(key: &[u8; 32], context: &[u8], input: &[u8]) -> Self {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+        mac.update(&(context.len() as u64).to_be_bytes());
+        mac.update(context);
+        mac.update(input);
+        let tag = mac.finalize().into_bytes();
+
+        let mut bytes = ZEROES;
+        bytes.copy_from_slice(&tag[..16]);
+        Yyid(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{std::string::ToString, *};
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    const KEY: [u8; 32] = {
+        let mut key = [0; 32];
+        let mut i = 0;
+        while i < 32 {
+            key[i] = i as u8;
+            i += 1;
+        }
+        key
+    };
+
+    #[test]
+    fn test_derive_keyed() {
+        assert_eq!(
+            Yyid::derive_keyed(&KEY, b"user-email", b"alice@example.com").to_string(),
+            "d7fc6fe9-9dc1-2429-7ad2-978280cfa436"
+        );
+    }
+
+    #[test]
+    fn test_derive_keyed_separation() {
+        let yyid = Yyid::derive_keyed(&KEY, b"ab", b"c");
+
+        assert_ne!(yyid, Yyid::derive_keyed(&KEY, b"a", b"bc"));
+        assert_ne!(yyid, Yyid::derive_keyed(&[0; 32], b"ab", b"c"));
+        assert_ne!(yyid, Yyid::derive_keyed(&KEY, b"ab", b"d"));
+    }
+}
@@ -14,8 +14,27 @@
 //!
 //! println!("{}", Yyid::new());
 //! // => "02e7f0f6-067e-8c92-b25c-12c9180540a9"
+//!
+//! let yyid: Yyid = "02e7f0f6-067e-8c92-b25c-12c9180540a9".parse().unwrap();
+//! assert_eq!(yyid, yyid!("02e7f0f6-067e-8c92-b25c-12c9180540a9"));
 //! ```
 //!
+//! ### Sources of randomness
+//!
+//! [`Yyid::new`] and friends draw from the first available of:
+//!
+//! 1. the thread's seeded generator, installed with `seeded::with_seeded`
+//!    (features `seeded` and `std`);
+//! 2. the process-wide seeded generator, installed with
+//!    `seeded::install_global` (features `seeded` and `std`);
+//! 3. a thread-local [`BufferedGenerator`](buffered::BufferedGenerator)
+//!    (feature `buffered`);
+//! 4. a thread-local RNG of the `rand` crate, seeded from the operating
+//!    system (feature `fast-rng`);
+//! 5. the operating system, via `getrandom`.
+//!
+//! So enabling `buffered` takes precedence over `fast-rng`.
+//!
 //! ### Other libraries for YYIDs
 //!
 //! - Ruby: <https://github.com/janlelis/yyid.rb>
@@ -41,13 +60,38 @@ extern crate std;
 #[cfg(all(not(feature = "std"), not(test)))]
 extern crate core as std;
 
-use std::fmt;
+use std::{fmt, mem, slice};
 
+pub mod buffered;
+#[cfg(feature = "cipher")]
+pub mod cipher;
+mod error;
 pub mod fmts;
+pub mod generator;
+#[cfg(feature = "keyed")]
+mod keyed;
+mod macros;
+pub mod monotonic;
+#[cfg(feature = "name-based")]
+mod name;
+mod parser;
+#[cfg(feature = "signed")]
+pub mod signed;
+
+#[cfg(feature = "rand")]
+pub mod rng;
+
+#[cfg(feature = "seeded")]
+pub mod seeded;
+
+mod time;
 
 #[cfg(feature = "uuid")]
 pub mod uuid;
 
+pub use error::{EntropyError, Error, ErrorKind};
+pub use generator::YyidGenerator;
+
 /// A 128-bit (16 byte) buffer containing the ID.
 pub type Bytes = [u8; 16];
 
@@ -58,26 +102,55 @@ const ZEROES: Bytes = [0; 16];
 #[repr(transparent)]
 pub struct Yyid(Bytes);
 
+/// The variant of a UUID, as encoded in the most significant bits of octet 8.
+///
+/// See [`Yyid::uuid_variant`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum UuidVariant {
+    /// `0xx`: reserved for NCS backward compatibility (includes the nil UUID).
+    Ncs,
+    /// `10x`: the variant specified by [RFC 9562](https://www.rfc-editor.org/rfc/rfc9562).
+    Rfc9562,
+    /// `110`: reserved for Microsoft backward compatibility.
+    Microsoft,
+    /// `111`: reserved for future definition (includes the max UUID).
+    Future,
+}
+
+/// Fills `yyids` with random bytes from the configured source, in one go.
+///
+/// The order of precedence is documented at the crate level.
 #[inline]
-fn bytes() -> Bytes {
-    #[cfg(not(feature = "fast-rng"))]
-    {
-        let mut bytes = ZEROES;
+fn try_fill(yyids: &mut [Yyid]) -> Result<(), Error> {
+    #[cfg(all(feature = "seeded", feature = "std"))]
+    if seeded::thread_fill(yyids) || seeded::global_fill(yyids) {
+        return Ok(());
+    }
 
-        getrandom::getrandom(&mut bytes).unwrap_or_else(|err| {
-            // NB: getrandom::Error has no source; this is adequate display
-            panic!("could not retrieve random bytes for uuid: {}", err)
-        });
+    #[cfg(feature = "buffered")]
+    {
+        buffered::thread_fill(yyids)
+    }
 
-        bytes
+    #[cfg(all(not(feature = "buffered"), not(feature = "fast-rng")))]
+    {
+        getrandom::fill(bytes_of_mut(yyids)).map_err(Error::entropy)
     }
 
-    #[cfg(feature = "fast-rng")]
+    #[cfg(all(not(feature = "buffered"), feature = "fast-rng"))]
     {
-        rand::random()
+        rng::thread_fill(yyids)
     }
 }
 
+/// Views a slice of YYIDs as a slice of their bytes.
+#[inline]
+pub(crate) fn bytes_of_mut(yyids: &mut [Yyid]) -> &mut [u8] {
+    // SAFETY: `Yyid` is a transparent wrapper around `[u8; 16]`,
+    //         so a slice of them is a contiguous slice of bytes
+    unsafe { slice::from_raw_parts_mut(yyids.as_mut_ptr().cast::<u8>(), mem::size_of_val(yyids)) }
+}
+
 impl Yyid {
     /// Creates a new random YYID
     ///
@@ -89,8 +162,92 @@ impl Yyid {
     /// println!("{}", yyid);
     /// // => "c49b79f5-22d4-dc42-f214-f4209c80d048"
     /// ```
+    ///
+    /// ### Panics
+    /// Panics if the source of randomness fails; use [`Yyid::try_new`] to
+    /// handle this case.
     pub fn new() -> Self {
-        Yyid(bytes())
+        Self::try_new().unwrap_or_else(|err| panic!("could not create a new YYID: {}", err))
+    }
+
+    /// Creates a new random YYID, returning an error if the configured source
+    /// of randomness failed.
+    ///
+    /// The error is of kind:
+    ///
+    /// - [`ErrorKind::Entropy`] if no random bytes could be retrieved, e.g. in
+    ///   sandboxed environments (seccomp) or very early during boot, before
+    ///   the entropy pool got initialized;
+    /// - [`ErrorKind::ForkHandler`] if the `buffered` generator could not
+    ///   register its fork handler.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::Yyid;
+    ///
+    /// match Yyid::try_new() {
+    ///     Ok(yyid) => println!("{}", yyid),
+    ///     Err(err) => eprintln!("{}", err),
+    /// }
+    /// ```
+    pub fn try_new() -> Result<Self, Error> {
+        let mut yyid = Yyid::nil();
+        try_fill(slice::from_mut(&mut yyid))?;
+        Ok(yyid)
+    }
+
+    /// Creates `N` new random YYIDs, retrieving the random bytes for all of
+    /// them at once.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::Yyid;
+    ///
+    /// let [a, b, c] = Yyid::new_batch::<3>();
+    /// assert!(a != b && b != c);
+    /// ```
+    ///
+    /// ### Panics
+    /// Panics if the source of randomness fails; use [`Yyid::try_new_batch`]
+    /// to handle this case.
+    pub fn new_batch<const N: usize>() -> [Self; N] {
+        Self::try_new_batch().unwrap_or_else(|err| panic!("could not create new YYIDs: {}", err))
+    }
+
+    /// Creates `N` new random YYIDs, returning an error if no random bytes
+    /// could be retrieved.
+    pub fn try_new_batch<const N: usize>() -> Result<[Self; N], Error> {
+        let mut yyids = [Yyid::nil(); N];
+        try_fill(&mut yyids)?;
+        Ok(yyids)
+    }
+
+    /// Overwrites every YYID in `yyids` with a new random one, retrieving the
+    /// random bytes for all of them with a single request.
+    ///
+    /// This is considerably faster than calling [`Yyid::new`] in a loop when
+    /// generating many YYIDs at once.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::Yyid;
+    ///
+    /// let mut yyids = vec![Yyid::nil(); 1000];
+    /// Yyid::fill(&mut yyids);
+    /// assert!(yyids.iter().all(|yyid| !yyid.is_nil()));
+    /// ```
+    ///
+    /// ### Panics
+    /// Panics if the source of randomness fails; use [`Yyid::try_fill`] to
+    /// handle this case.
+    pub fn fill(yyids: &mut [Yyid]) {
+        Self::try_fill(yyids).unwrap_or_else(|err| panic!("could not create new YYIDs: {}", err))
+    }
+
+    /// Overwrites every YYID in `yyids` with a new random one, returning an
+    /// error if no random bytes could be retrieved.
+    pub fn try_fill(yyids: &mut [Yyid]) -> Result<(), Error> {
+        try_fill(yyids)
     }
 
     /// Special case: a "nil" YYID
@@ -145,6 +302,50 @@ impl Yyid {
     pub fn as_u128_le(&self) -> u128 {
         u128::from_le_bytes(self.0)
     }
+
+    /// Interprets the YYID as a UUID and returns its [`UuidVariant`].
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::{UuidVariant, Yyid};
+    ///
+    /// let yyid: Yyid = "c49b79f5-22d4-4c42-b214-f4209c80d048".parse().unwrap();
+    /// assert_eq!(yyid.uuid_variant(), UuidVariant::Rfc9562);
+    ///
+    /// let yyid: Yyid = "c49b79f5-22d4-dc42-f214-f4209c80d048".parse().unwrap();
+    /// assert_eq!(yyid.uuid_variant(), UuidVariant::Future);
+    /// ```
+    pub const fn uuid_variant(&self) -> UuidVariant {
+        match self.0[8] >> 5 {
+            0b000..=0b011 => UuidVariant::Ncs,
+            0b100..=0b101 => UuidVariant::Rfc9562,
+            0b110 => UuidVariant::Microsoft,
+            _ => UuidVariant::Future,
+        }
+    }
+
+    /// Interprets the YYID as a UUID and returns its version.
+    ///
+    /// Returns `None` unless the YYID has the [`UuidVariant::Rfc9562`] variant and
+    /// one of the versions 1 to 8 defined by RFC 9562. Therefore both the nil
+    /// and the max UUID have no version.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::Yyid;
+    ///
+    /// let yyid: Yyid = "c49b79f5-22d4-4c42-b214-f4209c80d048".parse().unwrap();
+    /// assert_eq!(yyid.uuid_version(), Some(4));
+    ///
+    /// let yyid: Yyid = "c49b79f5-22d4-dc42-f214-f4209c80d048".parse().unwrap();
+    /// assert_eq!(yyid.uuid_version(), None);
+    /// ```
+    pub const fn uuid_version(&self) -> Option<u8> {
+        match (self.uuid_variant(), self.0[6] >> 4) {
+            (UuidVariant::Rfc9562, version @ 1..=8) => Some(version),
+            _ => None,
+        }
+    }
 }
 
 impl Default for Yyid {
@@ -196,6 +397,62 @@ mod tests {
         assert!(ystr.chars().all(|c| c.is_ascii_hexdigit() || c == '-'));
     }
 
+    #[test]
+    fn test_try_new() {
+        let yyid1 = Yyid::try_new().unwrap();
+        let yyid2 = Yyid::try_new().unwrap();
+
+        assert!(!yyid1.is_nil());
+        assert_ne!(yyid1, yyid2);
+    }
+
+    #[test]
+    fn test_new_batch() {
+        let yyids = Yyid::new_batch::<64>();
+        let set: std::collections::HashSet<_> = yyids.iter().collect();
+
+        assert_eq!(set.len(), 64);
+        assert!(!set.contains(&Yyid::nil()));
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut yyids = [Yyid::nil(); 64];
+        Yyid::fill(&mut yyids);
+        let set: std::collections::HashSet<_> = yyids.iter().collect();
+
+        assert_eq!(set.len(), 64);
+        assert!(!set.contains(&Yyid::nil()));
+
+        Yyid::fill(&mut []);
+    }
+
+    #[test]
+    fn test_uuid_variant_and_version() {
+        let yyid = |s| Yyid::parse_str(s).unwrap();
+
+        assert_eq!(Yyid::nil().uuid_variant(), UuidVariant::Ncs);
+        assert_eq!(Yyid::nil().uuid_version(), None);
+        assert_eq!(
+            yyid("ffffffff-ffff-ffff-ffff-ffffffffffff").uuid_variant(),
+            UuidVariant::Future
+        );
+        assert_eq!(yyid("ffffffff-ffff-ffff-ffff-ffffffffffff").uuid_version(), None);
+
+        assert_eq!(yyid("c49b79f5-22d4-1c42-9214-f4209c80d048").uuid_version(), Some(1));
+        assert_eq!(yyid("c49b79f5-22d4-7c42-a214-f4209c80d048").uuid_version(), Some(7));
+        assert_eq!(yyid("c49b79f5-22d4-9c42-a214-f4209c80d048").uuid_version(), None);
+        assert_eq!(
+            yyid("c49b79f5-22d4-4c42-c214-f4209c80d048").uuid_variant(),
+            UuidVariant::Microsoft
+        );
+        assert_eq!(yyid("c49b79f5-22d4-4c42-c214-f4209c80d048").uuid_version(), None);
+        assert_eq!(
+            yyid("c49b79f5-22d4-4c42-7214-f4209c80d048").uuid_variant(),
+            UuidVariant::Ncs
+        );
+    }
+
     #[test]
     fn test_to_hyphenated_string() {
         let yyid = Yyid::new();
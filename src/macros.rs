@@ -0,0 +1,31 @@
+/// Parses a [`Yyid`](crate::Yyid) literal at compile time.
+///
+/// Accepts the same four hex formats as [`Yyid::parse_str`](crate::Yyid::parse_str).
+/// A malformed literal fails the compilation instead of panicking at runtime.
+///
+/// ### Example
+/// ```rust
+/// use yyid::{yyid, Yyid};
+///
+/// const TENANT_ROOT: Yyid = yyid!("c49b79f5-22d4-dc42-f214-f4209c80d048");
+///
+/// assert_eq!(TENANT_ROOT.to_string(), "c49b79f5-22d4-dc42-f214-f4209c80d048");
+/// ```
+///
+/// Invalid literals are rejected by the compiler:
+///
+/// ```compile_fail
+/// use yyid::{yyid, Yyid};
+///
+/// const BROKEN: Yyid = yyid!("c49b79f5-22d4-dc42-f214-f4209c80d04g");
+/// ```
+#[macro_export]
+macro_rules! yyid {
+    ($yyid:expr) => {{
+        const OUTPUT: $crate::Yyid = match $crate::Yyid::try_parse($yyid) {
+            ::core::result::Result::Ok(yyid) => yyid,
+            ::core::result::Result::Err(_) => ::core::panic!("invalid YYID literal"),
+        };
+        OUTPUT
+    }};
+}
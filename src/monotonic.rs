@@ -0,0 +1,182 @@
+//! Strictly increasing, time-ordered YYIDs
+//!
+//! A [`MonotonicGenerator`] creates [time-ordered](crate::Yyid::with_timestamp_ms)
+//! YYIDs, like ULIDs: the first YYID in a millisecond gets a random tail, every
+//! further one within the same millisecond is the previous YYID plus one. If
+//! the clock goes backwards, the generator sticks to the last timestamp, so
+//! the YYIDs keep increasing.
+//!
+//! A generator only orders the YYIDs it creates itself; share one instance
+//! (e.g. behind a `Mutex`) to order all YYIDs of a node.
+
+use crate::{
+    generator::{DefaultGenerator, YyidGenerator},
+    time::MAX_TIMESTAMP_MS,
+    Error, ErrorKind, Yyid,
+};
+
+/// Mask of the 80 random bits following the timestamp.
+const TAIL_MASK: u128 = (1 << 80) - 1;
+
+/// A generator of strictly increasing, time-ordered YYIDs.
+///
+/// It deliberately does not implement [`Clone`], as a copy would hand out the
+/// same YYIDs as the original within the same millisecond.
+///
+/// ### Example
+/// ```rust
+/// use yyid::monotonic::MonotonicGenerator;
+///
+/// let mut generator = MonotonicGenerator::new();
+///
+/// let first = generator.try_generate_at(1_700_000_000_000).unwrap();
+/// let second = generator.try_generate_at(1_700_000_000_000).unwrap();
+/// let third = generator.try_generate_at(1_699_999_999_999).unwrap();
+///
+/// assert!(first < second && second < third);
+/// assert_eq!(third.timestamp_ms(), 1_700_000_000_000);
+/// ```
+#[derive(Debug, Default)]
+pub struct MonotonicGenerator<G = DefaultGenerator> {
+    source: G,
+    last: Option<Yyid>,
+}
+
+impl MonotonicGenerator {
+    /// Creates a generator drawing its randomness like [`Yyid::new`].
+    pub const fn new() -> Self {
+        MonotonicGenerator::with_generator(DefaultGenerator)
+    }
+}
+
+impl<G: YyidGenerator> MonotonicGenerator<G> {
+    /// Creates a generator drawing the random tails from `source`.
+    pub const fn with_generator(source: G) -> Self {
+        MonotonicGenerator { source, last: None }
+    }
+
+    /// Returns the next YYID for the given milliseconds since the Unix epoch.
+    ///
+    /// Fails if the random source fails, or if the 80 bit tail overflows as
+    /// too many YYIDs were created within the same millisecond.
+    pub fn try_generate_at(&mut self, timestamp_ms: u64) -> Result<Yyid, Error> {
+        let timestamp_ms = timestamp_ms.min(MAX_TIMESTAMP_MS);
+
+        let next = match self.last {
+            Some(last) if timestamp_ms <= last.timestamp_ms() => {
+                let value = last.as_u128();
+                if value & TAIL_MASK == TAIL_MASK {
+                    return Err(Error(ErrorKind::MonotonicOverflow));
+                }
+                Yyid((value + 1).to_be_bytes())
+            }
+            _ => self.source.try_generate()?.with_timestamp_ms(timestamp_ms),
+        };
+
+        self.last = Some(next);
+        Ok(next)
+    }
+}
+
+/// Generates YYIDs for the current system time.
+#[cfg(feature = "std")]
+impl<G: YyidGenerator> YyidGenerator for MonotonicGenerator<G> {
+    #[inline]
+    fn try_generate(&mut self) -> Result<Yyid, Error> {
+        self.try_generate_at(crate::time::now_ms())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Always returns the same YYID.
+    struct Fixed(Yyid);
+
+    impl YyidGenerator for Fixed {
+        fn try_generate(&mut self) -> Result<Yyid, Error> {
+            Ok(self.0)
+        }
+    }
+
+    /// Always fails, like a disconnected hardware source.
+    struct Failing;
+
+    impl YyidGenerator for Failing {
+        fn try_generate(&mut self) -> Result<Yyid, Error> {
+            Err(Error::generator("source offline"))
+        }
+    }
+
+    #[test]
+    fn test_strictly_increasing() {
+        let mut generator = MonotonicGenerator::new();
+        let mut last = generator.try_generate_at(1_000).unwrap();
+
+        for ts in [1_000, 1_000, 1_001, 1_001, 1_001, 1_005] {
+            let next = generator.try_generate_at(ts).unwrap();
+            assert!(next > last);
+            assert_eq!(next.timestamp_ms(), ts);
+            last = next;
+        }
+    }
+
+    #[test]
+    fn test_same_millisecond_increments() {
+        let mut generator = MonotonicGenerator::with_generator(Fixed(Yyid::nil()));
+
+        let first = generator.try_generate_at(42).unwrap();
+        let second = generator.try_generate_at(42).unwrap();
+
+        assert_eq!(second.as_u128(), first.as_u128() + 1);
+    }
+
+    #[test]
+    fn test_clock_going_backwards() {
+        let mut generator = MonotonicGenerator::new();
+
+        let first = generator.try_generate_at(2_000).unwrap();
+        let second = generator.try_generate_at(1_000).unwrap();
+
+        assert!(second > first);
+        assert_eq!(second.timestamp_ms(), 2_000);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max_tail = Yyid(TAIL_MASK.to_be_bytes());
+        let mut generator = MonotonicGenerator::with_generator(Fixed(max_tail));
+
+        generator.try_generate_at(7).unwrap();
+        let err = generator.try_generate_at(7).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MonotonicOverflow);
+
+        // a new millisecond starts over with a fresh tail
+        assert_eq!(generator.try_generate_at(8).unwrap().timestamp_ms(), 8);
+    }
+
+    #[test]
+    fn test_source_error() {
+        let mut generator = MonotonicGenerator::with_generator(Failing);
+
+        let err = generator.try_generate_at(7).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Generator {
+                reason: "source offline"
+            }
+        );
+        assert_eq!(generator.last, None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_generate_now() {
+        let mut generator = MonotonicGenerator::new();
+        let yyids: std::vec::Vec<Yyid> = (0..1000).map(|_| generator.generate()).collect();
+
+        assert!(yyids.windows(2).all(|w| w[0] < w[1]));
+    }
+}
@@ -0,0 +1,71 @@
+//! Name-based YYIDs
+//!
+//! Like UUIDv5, but hashing with SHA-256 and using all 128 bits of the
+//! (truncated) digest.
+
+use crate::{Yyid, ZEROES};
+use sha2::{Digest, Sha256};
+
+impl Yyid {
+    /// Derives a YYID from a `namespace` and a `name`.
+    ///
+    /// The same inputs always result in the same YYID, which makes it possible
+    /// to derive stable IDs for external entities, e.g. an email address
+    /// within a tenant. The YYID consists of the first 16 bytes of the SHA-256
+    /// digest of the namespace bytes followed by the name.
+    ///
+    /// Anyone knowing the namespace can test for names, so the YYID leaks the
+    /// name if it is easy to guess; use `Yyid::derive_keyed` (feature `keyed`)
+    /// in that case.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::{yyid, Yyid};
+    ///
+    /// const TENANT: Yyid = yyid!("c49b79f5-22d4-dc42-f214-f4209c80d048");
+    ///
+    /// let user = Yyid::from_name(&TENANT, b"alice@example.com");
+    /// assert_eq!(user, Yyid::from_name(&TENANT, b"alice@example.com"));
+    /// assert_eq!(user.to_string(), "4c93be98-1b00-60bd-6762-2f6312b30f32");
+    /// ```
+    pub fn from_name(namespace: &Yyid, name: &[u8]) -> Self {
+        let digest = Sha256::new()
+            .chain_update(namespace.as_bytes())
+            .chain_update(name)
+            .finalize();
+
+        let mut bytes = ZEROES;
+        bytes.copy_from_slice(&digest[..16]);
+        Yyid(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{std::string::ToString, *};
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_from_name() {
+        let namespace = yyid!("c49b79f5-22d4-dc42-f214-f4209c80d048");
+
+        assert_eq!(
+            Yyid::from_name(&namespace, b"alice@example.com").to_string(),
+            "4c93be98-1b00-60bd-6762-2f6312b30f32"
+        );
+        assert_eq!(
+            Yyid::from_name(&Yyid::nil(), b"").to_string(),
+            "374708ff-f771-9dd5-979e-c875d56cd228"
+        );
+    }
+
+    #[test]
+    fn test_from_name_distinct() {
+        let namespace = Yyid::new();
+        let other = Yyid::new();
+
+        assert_eq!(Yyid::from_name(&namespace, b"a"), Yyid::from_name(&namespace, b"a"));
+        assert_ne!(Yyid::from_name(&namespace, b"a"), Yyid::from_name(&namespace, b"b"));
+        assert_ne!(Yyid::from_name(&namespace, b"a"), Yyid::from_name(&other, b"a"));
+    }
+}
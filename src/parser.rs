@@ -0,0 +1,371 @@
+//! Parsing YYIDs from their string representations
+// The approach follows <https://github.com/uuid-rs/uuid/blob/main/src/parser.rs>
+
+use crate::{
+    error::{Error, ErrorKind, GROUP_LENGTHS},
+    std::str,
+    Bytes, Yyid,
+};
+
+const URN_PREFIX: &[u8; 9] = b"urn:yyid:";
+
+/// Maps an ASCII byte to its hex value, or to `0xff` if it is not a hex digit.
+pub(crate) const HEX_TABLE: &[u8; 256] = &{
+    let mut buf = [0xff; 256];
+    let mut i: u8 = 0;
+    loop {
+        buf[i as usize] = match i {
+            b'0'..=b'9' => i - b'0',
+            b'a'..=b'f' => i - b'a' + 10,
+            b'A'..=b'F' => i - b'A' + 10,
+            _ => 0xff,
+        };
+        if i == 255 {
+            break buf;
+        }
+        i += 1;
+    }
+};
+
+impl Yyid {
+    /// Parses a [`Yyid`] from a string of hexadecimal digits.
+    ///
+    /// The four hex formats are supported, in lower, upper or mixed case:
+    ///
+    /// - [`Simple`](crate::fmts::Simple): `c49b79f522d4dc42f214f4209c80d048`
+    /// - [`Hyphenated`](crate::fmts::Hyphenated): `c49b79f5-22d4-dc42-f214-f4209c80d048`
+    /// - [`Urn`](crate::fmts::Urn): `urn:yyid:c49b79f5-22d4-dc42-f214-f4209c80d048`
+    /// - [`Braced`](crate::fmts::Braced): `{c49b79f5-22d4-dc42-f214-f4209c80d048}`
+    ///
+    /// The compact encodings are parsed by their own `parse_str`, like
+    /// [`Base32::parse_str`](crate::fmts::Base32::parse_str) or
+    /// [`Prefixed::parse_str`](crate::fmts::Prefixed::parse_str).
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::Yyid;
+    ///
+    /// let yyid = Yyid::parse_str("c49b79f5-22d4-dc42-f214-f4209c80d048").unwrap();
+    /// assert_eq!(yyid.to_string(), "c49b79f5-22d4-dc42-f214-f4209c80d048");
+    ///
+    /// let urn = Yyid::parse_str("urn:yyid:C49B79F5-22D4-DC42-F214-F4209C80D048").unwrap();
+    /// assert_eq!(yyid, urn);
+    /// ```
+    pub fn parse_str(input: &str) -> Result<Yyid, Error> {
+        Yyid::try_parse(input)
+    }
+
+    /// Parses a [`Yyid`] from a string of hexadecimal digits in a `const` context.
+    ///
+    /// This accepts the same formats as [`Yyid::parse_str`] and is the
+    /// counterpart of the `const` encoders behind [`fmts`](crate::fmts).
+    /// To turn a literal into a [`Yyid`] at compile time use the
+    /// [`yyid!`](crate::yyid!) macro.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::Yyid;
+    ///
+    /// const SYSTEM_USER: Yyid = match Yyid::try_parse("c49b79f5-22d4-dc42-f214-f4209c80d048") {
+    ///     Ok(yyid) => yyid,
+    ///     Err(_) => panic!("invalid system user id"),
+    /// };
+    ///
+    /// assert_eq!(SYSTEM_USER.to_string(), "c49b79f5-22d4-dc42-f214-f4209c80d048");
+    /// ```
+    pub const fn try_parse(input: &str) -> Result<Yyid, Error> {
+        match parse(input.as_bytes()) {
+            Ok(bytes) => Ok(Yyid(bytes)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl str::FromStr for Yyid {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Yyid::parse_str(s)
+    }
+}
+
+pub(crate) const fn parse(input: &[u8]) -> Result<Bytes, Error> {
+    match input {
+        [b'{', .., b'}'] => {
+            let (_, inner) = input.split_at(1);
+            let (inner, _) = inner.split_at(inner.len() - 1);
+            parse_hyphenated(inner, 1)
+        }
+        [b'{', ..] | [.., b'}'] => Err(Error(ErrorKind::Braces)),
+        _ if has_prefix_ignore_case(input, b"urn:") => {
+            if !has_prefix_ignore_case(input, URN_PREFIX) {
+                return Err(Error(ErrorKind::UrnPrefix));
+            }
+            let (_, inner) = input.split_at(URN_PREFIX.len());
+            parse_hyphenated(inner, URN_PREFIX.len())
+        }
+        _ if contains_hyphen(input) => parse_hyphenated(input, 0),
+        _ => parse_simple(input),
+    }
+}
+
+const fn has_prefix_ignore_case(s: &[u8], prefix: &[u8]) -> bool {
+    if s.len() < prefix.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if s[i].to_ascii_lowercase() != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn contains_hyphen(s: &[u8]) -> bool {
+    let mut i = 0;
+    while i < s.len() {
+        if s[i] == b'-' {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+const fn parse_simple(s: &[u8]) -> Result<Bytes, Error> {
+    if let Err(err) = check_chars(s, 0) {
+        return Err(err);
+    }
+    if s.len() != 32 {
+        return Err(Error(ErrorKind::Length { len: s.len() }));
+    }
+
+    let mut buf = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        buf[i] = HEX_TABLE[s[i * 2] as usize] * 16 + HEX_TABLE[s[i * 2 + 1] as usize];
+        i += 1;
+    }
+    Ok(buf)
+}
+
+/// Parses `c49b79f5-22d4-dc42-f214-f4209c80d048`, where `offset` is the
+/// position of `s` within the original input (for error reporting).
+const fn parse_hyphenated(s: &[u8], offset: usize) -> Result<Bytes, Error> {
+    if let Err(err) = check_chars(s, offset) {
+        return Err(err);
+    }
+    if let Err(err) = check_groups(s, offset) {
+        return Err(err);
+    }
+
+    // positions of the four digits of each pair of bytes
+    let positions: [u8; 8] = [0, 4, 9, 14, 19, 24, 28, 32];
+    let mut buf = [0; 16];
+    let mut j = 0;
+    while j < 8 {
+        let i = positions[j] as usize;
+
+        buf[j * 2] = HEX_TABLE[s[i] as usize] * 16 + HEX_TABLE[s[i + 1] as usize];
+        buf[j * 2 + 1] = HEX_TABLE[s[i + 2] as usize] * 16 + HEX_TABLE[s[i + 3] as usize];
+        j += 1;
+    }
+    Ok(buf)
+}
+
+/// Ensures `s` only consists of hex digits and hyphens.
+const fn check_chars(s: &[u8], offset: usize) -> Result<(), Error> {
+    let mut i = 0;
+    while i < s.len() {
+        if s[i] != b'-' && HEX_TABLE[s[i] as usize] == 0xff {
+            return Err(Error(ErrorKind::Char {
+                character: char_at(s, i),
+                index: offset + i,
+            }));
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Ensures `s` consists of 5 hyphen separated groups of the expected lengths.
+const fn check_groups(s: &[u8], offset: usize) -> Result<(), Error> {
+    let mut count = 1;
+    let mut i = 0;
+    while i < s.len() {
+        if s[i] == b'-' {
+            count += 1;
+        }
+        i += 1;
+    }
+    if count != GROUP_LENGTHS.len() {
+        return Err(Error(ErrorKind::GroupCount { count }));
+    }
+
+    let mut group = 0;
+    let mut start = 0;
+    let mut i = 0;
+    while i <= s.len() {
+        if i == s.len() || s[i] == b'-' {
+            let len = i - start;
+            if len != GROUP_LENGTHS[group] {
+                return Err(Error(ErrorKind::GroupLength {
+                    group,
+                    len,
+                    index: offset + start,
+                }));
+            }
+            group += 1;
+            start = i + 1;
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Decodes the UTF-8 encoded character starting at `s[i]`.
+pub(crate) const fn char_at(s: &[u8], i: usize) -> char {
+    let b = s[i];
+    let (len, init) = match b {
+        0x00..=0x7f => return b as char,
+        0xc0..=0xdf => (2, (b & 0x1f) as u32),
+        0xe0..=0xef => (3, (b & 0x0f) as u32),
+        0xf0..=0xf7 => (4, (b & 0x07) as u32),
+        _ => return char::REPLACEMENT_CHARACTER,
+    };
+    if i + len > s.len() {
+        return char::REPLACEMENT_CHARACTER;
+    }
+
+    let mut code = init;
+    let mut j = 1;
+    while j < len {
+        code = (code << 6) | (s[i + j] & 0x3f) as u32;
+        j += 1;
+    }
+    match char::from_u32(code) {
+        Some(c) => c,
+        None => char::REPLACEMENT_CHARACTER,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{std::string::ToString, *};
+    use pretty_assertions::assert_eq;
+
+    const HYPHENATED: &str = "c49b79f5-22d4-dc42-f214-f4209c80d048";
+
+    #[test]
+    fn test_parse_all_formats() {
+        let yyid = Yyid::parse_str(HYPHENATED).unwrap();
+
+        assert_eq!(Yyid::parse_str("c49b79f522d4dc42f214f4209c80d048"), Ok(yyid));
+        assert_eq!(
+            Yyid::parse_str("urn:yyid:c49b79f5-22d4-dc42-f214-f4209c80d048"),
+            Ok(yyid)
+        );
+        assert_eq!(Yyid::parse_str("{c49b79f5-22d4-dc42-f214-f4209c80d048}"), Ok(yyid));
+        assert_eq!(yyid.to_string(), HYPHENATED);
+    }
+
+    #[test]
+    fn test_parse_any_case() {
+        let yyid = Yyid::parse_str(HYPHENATED).unwrap();
+
+        assert_eq!(Yyid::parse_str("C49B79F5-22D4-DC42-F214-F4209C80D048"), Ok(yyid));
+        assert_eq!(Yyid::parse_str("c49B79f5-22D4-dC42-F214-f4209c80D048"), Ok(yyid));
+        assert_eq!(
+            Yyid::parse_str("URN:YYID:C49B79F5-22D4-DC42-F214-F4209C80D048"),
+            Ok(yyid)
+        );
+    }
+
+    #[test]
+    fn test_try_parse_const() {
+        const YYID: Yyid = yyid!("c49b79f5-22d4-dc42-f214-f4209c80d048");
+        const BRACED: Result<Yyid, Error> = Yyid::try_parse("{C49B79F5-22D4-DC42-F214-F4209C80D048}");
+        const INVALID: Result<Yyid, Error> = Yyid::try_parse("c49b79f5-22d4-dc42-f214-f4209c80d04");
+
+        assert_eq!(YYID.to_string(), HYPHENATED);
+        assert_eq!(BRACED, Ok(YYID));
+        assert_eq!(Yyid::parse_str(HYPHENATED), Ok(YYID));
+        assert!(INVALID.is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for _ in 0..100 {
+            let yyid = Yyid::new();
+
+            assert_eq!(yyid.to_string().parse::<Yyid>(), Ok(yyid));
+            assert_eq!(yyid.as_simple().to_string().parse::<Yyid>(), Ok(yyid));
+            assert_eq!(yyid.as_urn().to_string().parse::<Yyid>(), Ok(yyid));
+            assert_eq!(yyid.as_braced().to_string().parse::<Yyid>(), Ok(yyid));
+            assert_eq!(std::format!("{yyid:X}").parse::<Yyid>(), Ok(yyid));
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = |s| Yyid::parse_str(s).unwrap_err().kind();
+
+        assert_eq!(err(""), ErrorKind::Length { len: 0 });
+        assert_eq!(err("c49b79f522d4dc42f214f4209c80d0"), ErrorKind::Length { len: 30 });
+        assert_eq!(
+            err("g49b79f5-22d4-dc42-f214-f4209c80d048"),
+            ErrorKind::Char {
+                character: 'g',
+                index: 0
+            }
+        );
+        assert_eq!(
+            err("urn:yyid:c49b79f5-22d4-dc42-f214-f4209c80d0\u{e9}8"),
+            ErrorKind::Char {
+                character: '\u{e9}',
+                index: 43
+            }
+        );
+        assert_eq!(
+            err("{c49b79f5-22d4-dc42-f214-f4209c80d04}"),
+            ErrorKind::GroupLength {
+                group: 4,
+                len: 11,
+                index: 25
+            }
+        );
+        assert_eq!(
+            err("c49b79f5a-2d4-dc42-f214-f4209c80d048"),
+            ErrorKind::GroupLength {
+                group: 0,
+                len: 9,
+                index: 0
+            }
+        );
+        assert_eq!(
+            err("c49b79f5-22d4-dc42-f214f4209c80d048"),
+            ErrorKind::GroupCount { count: 4 }
+        );
+        assert_eq!(
+            err("urn:uuid:c49b79f5-22d4-dc42-f214-f4209c80d048"),
+            ErrorKind::UrnPrefix
+        );
+        assert_eq!(err("c49b79f5-22d4-dc42-f214-f4209c80d048}"), ErrorKind::Braces);
+        assert_eq!(err("{c49b79f5-22d4-dc42-f214-f4209c80d048"), ErrorKind::Braces);
+        assert_eq!(err("{c49b79f5-22d4-dc42-f214-f4209c80d048]"), ErrorKind::Braces);
+        assert_eq!(
+            err("c49b79f5+22d4-dc42-f214-f4209c80d048"),
+            ErrorKind::Char {
+                character: '+',
+                index: 8
+            }
+        );
+        assert_eq!(
+            err("c49b79f5-22d4-dc42-f214-f4209c80d048-"),
+            ErrorKind::GroupCount { count: 6 }
+        );
+    }
+}
@@ -0,0 +1,211 @@
+//! Generating YYIDs from [`rand`] random number generators
+//!
+//! With the `fast-rng` feature enabled (and `buffered` not), [`Yyid::new`] and
+//! friends draw from a thread-local [`rand::rngs::StdRng`], seeded from the
+//! operating system and reseeded after every 64 KiB, like `rand::rng()`.
+//! Unlike `rand::rng()`, a failing operating system is reported as an error
+//! instead of a panic.
+
+#[cfg(all(feature = "fast-rng", not(feature = "buffered")))]
+use crate::{bytes_of_mut, std::cell::RefCell};
+use crate::{Error, Yyid, YyidGenerator, ZEROES};
+use rand::{
+    distr::{Distribution, StandardUniform},
+    CryptoRng, Rng, RngCore,
+};
+#[cfg(all(feature = "fast-rng", not(feature = "buffered")))]
+use rand::{rngs::StdRng, SeedableRng};
+
+impl Yyid {
+    /// Creates a new random YYID from the given random number generator.
+    ///
+    /// The quality of the YYID depends entirely on `rng`; prefer
+    /// [`Yyid::from_crypto_rng`] whenever the YYIDs must not be guessable.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use yyid::Yyid;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let yyid = Yyid::from_rng(&mut rng);
+    /// println!("{}", yyid);
+    /// ```
+    pub fn from_rng<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut bytes = ZEROES;
+        rng.fill_bytes(&mut bytes);
+        Yyid(bytes)
+    }
+
+    /// Creates a new random YYID from the given cryptographically secure
+    /// random number generator.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::Yyid;
+    ///
+    /// let yyid = Yyid::from_crypto_rng(&mut rand::rng());
+    /// println!("{}", yyid);
+    /// ```
+    pub fn from_crypto_rng<R: CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_rng(rng)
+    }
+}
+
+/// A [`YyidGenerator`] drawing from a `rand` random number generator.
+///
+/// ### Example
+/// ```rust
+/// use rand::{rngs::StdRng, SeedableRng};
+/// use yyid::{rng::RngGenerator, YyidGenerator};
+///
+/// let mut generator = RngGenerator(StdRng::seed_from_u64(42));
+/// println!("{}", generator.generate());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RngGenerator<R>(pub R);
+
+impl<R: RngCore> YyidGenerator for RngGenerator<R> {
+    #[inline]
+    fn try_generate(&mut self) -> Result<Yyid, Error> {
+        Ok(Yyid::from_rng(&mut self.0))
+    }
+}
+
+/// Samples random YYIDs, so they can be used with [`Rng::random`] and
+/// [`Rng::sample_iter`] like any primitive type.
+///
+/// ### Example
+/// ```rust
+/// use rand::{distr::StandardUniform, Rng};
+/// use yyid::Yyid;
+///
+/// let mut rng = rand::rng();
+///
+/// let yyid: Yyid = rng.random();
+/// println!("{}", yyid);
+///
+/// let yyids: Vec<Yyid> = rng.sample_iter(StandardUniform).take(3).collect();
+/// assert_eq!(yyids.len(), 3);
+/// ```
+impl Distribution<Yyid> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Yyid {
+        Yyid::from_rng(rng)
+    }
+}
+
+/// Number of bytes handed out by the thread-local RNG before it reseeds.
+#[cfg(all(feature = "fast-rng", not(feature = "buffered")))]
+const RESEED_THRESHOLD: usize = 64 * 1024;
+
+#[cfg(all(feature = "fast-rng", not(feature = "buffered")))]
+struct FastRng {
+    rng: StdRng,
+    until_reseed: usize,
+}
+
+#[cfg(all(feature = "fast-rng", not(feature = "buffered")))]
+impl FastRng {
+    fn try_new() -> Result<Self, Error> {
+        let mut seed = [0; 32];
+        getrandom::fill(&mut seed).map_err(Error::entropy)?;
+        Ok(FastRng {
+            rng: StdRng::from_seed(seed),
+            until_reseed: RESEED_THRESHOLD,
+        })
+    }
+}
+
+#[cfg(all(feature = "fast-rng", not(feature = "buffered")))]
+std::thread_local! {
+    static THREAD_RNG: RefCell<Option<FastRng>> = const { RefCell::new(None) };
+}
+
+/// Fills `yyids` from the thread-local RNG, seeding it first if needed.
+#[cfg(all(feature = "fast-rng", not(feature = "buffered")))]
+pub(crate) fn thread_fill(yyids: &mut [Yyid]) -> Result<(), Error> {
+    let dest = bytes_of_mut(yyids);
+    THREAD_RNG.with(|cell| {
+        let mut state = cell.borrow_mut();
+        let fast = match &mut *state {
+            Some(fast) if fast.until_reseed > 0 => fast,
+            slot => slot.insert(FastRng::try_new()?),
+        };
+        fast.rng.fill_bytes(dest);
+        fast.until_reseed = fast.until_reseed.saturating_sub(dest.len());
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RngGenerator;
+    use crate::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+    use rand::{distr::StandardUniform, rngs::StdRng, Rng, SeedableRng};
+    use std::vec::Vec;
+
+    #[test]
+    fn test_from_rng_seeded() {
+        let mut rng1 = StdRng::seed_from_u64(42);
+        let mut rng2 = StdRng::seed_from_u64(42);
+
+        let yyid1 = Yyid::from_rng(&mut rng1);
+        let yyid2 = Yyid::from_rng(&mut rng2);
+
+        assert_eq!(yyid1, yyid2);
+        assert_ne!(yyid1, Yyid::from_rng(&mut rng1));
+    }
+
+    #[test]
+    fn test_from_crypto_rng() {
+        let mut rng = rand::rng();
+        let yyid1 = Yyid::from_crypto_rng(&mut rng);
+        let yyid2 = Yyid::from_crypto_rng(&mut rng);
+
+        assert!(!yyid1.is_nil());
+        assert_ne!(yyid1, yyid2);
+    }
+
+    #[test]
+    fn test_rng_generator() {
+        let mut generator = RngGenerator(StdRng::seed_from_u64(42));
+
+        assert_eq!(generator.generate(), Yyid::from_rng(&mut StdRng::seed_from_u64(42)));
+        assert_ne!(generator.try_generate().unwrap(), generator.generate());
+    }
+
+    #[test]
+    fn test_distribution() {
+        let mut rng1 = StdRng::seed_from_u64(7);
+        let mut rng2 = StdRng::seed_from_u64(7);
+
+        let yyid: Yyid = rng1.random();
+        assert_eq!(yyid, Yyid::from_rng(&mut rng2));
+
+        let yyids: Vec<Yyid> = rng1.sample_iter(StandardUniform).take(10).collect();
+        assert_eq!(yyids.len(), 10);
+        assert!(yyids.iter().all(|yyid| !yyid.is_nil()));
+    }
+
+    #[cfg(all(feature = "fast-rng", not(feature = "buffered")))]
+    #[test]
+    fn test_thread_fill_reseeds() {
+        let mut yyids = std::vec![Yyid::nil(); super::RESEED_THRESHOLD / 16];
+        super::thread_fill(&mut yyids).unwrap();
+        assert_eq!(
+            super::THREAD_RNG.with(|cell| cell.borrow().as_ref().map(|fast| fast.until_reseed)),
+            Some(0)
+        );
+
+        let mut next = [Yyid::nil(); 2];
+        super::thread_fill(&mut next).unwrap();
+        assert_eq!(
+            super::THREAD_RNG.with(|cell| cell.borrow().as_ref().map(|fast| fast.until_reseed)),
+            Some(super::RESEED_THRESHOLD - 32)
+        );
+        assert!(!next[0].is_nil());
+        assert_ne!(next[0], next[1]);
+    }
+}
@@ -0,0 +1,346 @@
+//! Tamper-evident YYID tokens
+//!
+//! A [`SignedYyid`] appends a truncated HMAC-SHA256 tag to the 16 bytes of a
+//! YYID. Tokens that were forged or corrupted are rejected by
+//! [`SignedYyid::verify`] before they ever reach the database.
+
+use crate::{
+    fmts::{base64url_len, format_base64url_bytes, parse_base64url_bytes, BASE64_URL_TABLE, LOWER, UPPER},
+    parser::{char_at, HEX_TABLE},
+    std::{fmt, str},
+    Error, ErrorKind, Yyid,
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Separates signatures from other uses of the same key.
+const DOMAIN: &[u8] = b"yyid:signed:";
+
+/// Length of the longest encoded [`SignedYyid`], with a 32 byte tag.
+const MAX_LENGTH: usize = 32 + 2 * 32;
+
+/// Number of bytes of the longest [`SignedYyid`], with a 32 byte tag.
+const MAX_BYTES: usize = 16 + 32;
+
+/// A [`Yyid`] with an `N` byte authentication tag.
+///
+/// `N` must be between 4 and 32; the default of 8 bytes (64 bits) makes
+/// guessing a valid token for a given YYID practically impossible for an
+/// online attacker. The token is encoded like a [`Simple`](crate::fmts::Simple)
+/// YYID, followed by the hex encoded tag. For links,
+/// [`SignedYyid::encode_base64url`] gives a shorter token of 32 instead of 48
+/// characters.
+///
+/// ### Example
+/// ```rust
+/// use yyid::{signed::SignedYyid, Yyid};
+///
+/// let key = [7u8; 32]; // rotating the key invalidates all issued tokens
+/// let yyid = Yyid::new();
+///
+/// let token = SignedYyid::<8>::sign(yyid, &key).to_string();
+/// assert_eq!(token.len(), 48);
+///
+/// let signed: SignedYyid = token.parse().unwrap();
+/// assert_eq!(signed.verify(&key), Ok(yyid));
+/// assert!(signed.verify(&[8; 32]).is_err());
+/// ```
+///
+/// Tags of unsupported lengths are rejected by the compiler:
+///
+/// ```compile_fail
+/// use yyid::{signed::SignedYyid, Yyid};
+///
+/// let signed = SignedYyid::<2>::sign(Yyid::new(), &[7; 32]);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SignedYyid<const N: usize = 8> {
+    yyid: Yyid,
+    tag: [u8; N],
+}
+
+impl<const N: usize> SignedYyid<N> {
+    /// Encoded string length
+    pub const LENGTH: usize = 32 + 2 * N;
+
+    /// Base64Url encoded string length
+    pub const BASE64URL_LENGTH: usize = base64url_len(16 + N);
+
+    const VALID_TAG_LENGTH: () = assert!(N >= 4 && N <= 32, "the tag of a SignedYyid must have 4 to 32 bytes");
+
+    /// Signs `yyid` with `key`.
+    pub fn sign(yyid: Yyid, key: &[u8; 32]) -> Self {
+        let () = Self::VALID_TAG_LENGTH;
+
+        let tag = mac(&yyid, key).finalize().into_bytes();
+        let mut truncated = [0; N];
+        truncated.copy_from_slice(&tag[..N]);
+        SignedYyid { yyid, tag: truncated }
+    }
+
+    /// Checks the tag with `key`, and returns the YYID if it is authentic.
+    ///
+    /// The comparison takes constant time.
+    pub fn verify(&self, key: &[u8; 32]) -> Result<Yyid, Error> {
+        mac(&self.yyid, key)
+            .verify_truncated_left(&self.tag)
+            .map(|()| self.yyid)
+            .map_err(|_| Error(ErrorKind::Signature))
+    }
+
+    /// Get a reference to the YYID, **without** verifying it.
+    pub const fn as_yyid_unverified(&self) -> &Yyid {
+        &self.yyid
+    }
+
+    /// Get a reference to the tag.
+    pub const fn tag(&self) -> &[u8; N] {
+        &self.tag
+    }
+
+    /// Parses a token as encoded by [`SignedYyid::encode_lower`] or
+    /// [`SignedYyid::encode_upper`], **without** verifying it.
+    pub fn parse_str(input: &str) -> Result<Self, Error> {
+        let () = Self::VALID_TAG_LENGTH;
+
+        let s = input.as_bytes();
+        if s.len() != Self::LENGTH {
+            return Err(Error(ErrorKind::FormatLength {
+                expected: Self::LENGTH,
+                len: s.len(),
+            }));
+        }
+
+        let mut yyid = Yyid::nil();
+        let mut tag = [0; N];
+        for (i, byte) in yyid.0.iter_mut().chain(tag.iter_mut()).enumerate() {
+            let (h1, h2) = (HEX_TABLE[s[i * 2] as usize], HEX_TABLE[s[i * 2 + 1] as usize]);
+            if h1 | h2 == 0xff {
+                let index = if h1 == 0xff { i * 2 } else { i * 2 + 1 };
+                return Err(Error(ErrorKind::Digit {
+                    character: char_at(s, index),
+                    index,
+                    expected: "[0-9a-fA-F]",
+                }));
+            }
+            *byte = h1 * 16 + h2;
+        }
+        Ok(SignedYyid { yyid, tag })
+    }
+
+    /// Parses a token as encoded by [`SignedYyid::encode_base64url`],
+    /// **without** verifying it.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::{signed::SignedYyid, Yyid};
+    ///
+    /// let key = [7u8; 32];
+    /// let signed = SignedYyid::<8>::sign(Yyid::new(), &key);
+    ///
+    /// let token = signed.encode_base64url(&mut [0; SignedYyid::<8>::BASE64URL_LENGTH]).to_string();
+    /// assert_eq!(token.len(), 32);
+    /// assert_eq!(SignedYyid::<8>::parse_base64url(&token), Ok(signed));
+    /// ```
+    pub fn parse_base64url(input: &str) -> Result<Self, Error> {
+        let () = Self::VALID_TAG_LENGTH;
+
+        let s = input.as_bytes();
+        if s.len() != Self::BASE64URL_LENGTH {
+            return Err(Error(ErrorKind::FormatLength {
+                expected: Self::BASE64URL_LENGTH,
+                len: s.len(),
+            }));
+        }
+
+        let mut bytes = [0; MAX_BYTES];
+        parse_base64url_bytes(s, &mut bytes[..16 + N], BASE64_URL_TABLE, "[A-Za-z0-9-_]")?;
+
+        let mut yyid = Yyid::nil();
+        let mut tag = [0; N];
+        yyid.0.copy_from_slice(&bytes[..16]);
+        tag.copy_from_slice(&bytes[16..16 + N]);
+        Ok(SignedYyid { yyid, tag })
+    }
+
+    /// Writes the token as an unpadded, URL-safe Base64 string to `buffer`,
+    /// and returns the subslice of the buffer that contains the encoded token.
+    pub fn encode_base64url<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        let mut bytes = [0; MAX_BYTES];
+        bytes[..16].copy_from_slice(&self.yyid.0);
+        bytes[16..16 + N].copy_from_slice(&self.tag);
+
+        let buf = &mut buffer[..Self::BASE64URL_LENGTH];
+        format_base64url_bytes(&bytes[..16 + N], buf);
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+
+    /// Writes the token as a lower-case hex string to `buffer`, and returns
+    /// the subslice of the buffer that contains the encoded token.
+    #[inline]
+    pub fn encode_lower<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        self.encode(buffer, &LOWER)
+    }
+
+    /// Writes the token as an upper-case hex string to `buffer`, and returns
+    /// the subslice of the buffer that contains the encoded token.
+    #[inline]
+    pub fn encode_upper<'buf>(&self, buffer: &'buf mut [u8]) -> &'buf mut str {
+        self.encode(buffer, &UPPER)
+    }
+
+    fn encode<'buf>(&self, buffer: &'buf mut [u8], lut: &[u8; 16]) -> &'buf mut str {
+        let buf = &mut buffer[..Self::LENGTH];
+        for (dst, x) in buf.chunks_exact_mut(2).zip(self.yyid.0.iter().chain(self.tag.iter())) {
+            dst[0] = lut[(x >> 4) as usize];
+            dst[1] = lut[(x & 0x0f) as usize];
+        }
+
+        // SAFETY: The encoded buffer is ASCII encoded
+        unsafe { str::from_utf8_unchecked_mut(buf) }
+    }
+}
+
+fn mac(yyid: &Yyid, key: &[u8; 32]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(DOMAIN);
+    mac.update(yyid.as_bytes());
+    mac
+}
+
+impl<const N: usize> str::FromStr for SignedYyid<N> {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SignedYyid::parse_str(s)
+    }
+}
+
+impl<const N: usize> fmt::Display for SignedYyid<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for SignedYyid<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode_lower(&mut [0; MAX_LENGTH]))
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for SignedYyid<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.encode_upper(&mut [0; MAX_LENGTH]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::std::{format, string::ToString};
+    use pretty_assertions::assert_eq;
+
+    const KEY: [u8; 32] = [42; 32];
+
+    #[test]
+    fn test_sign_and_verify() {
+        let yyid = Yyid::new();
+        let signed = SignedYyid::<8>::sign(yyid, &KEY);
+
+        assert_eq!(signed.verify(&KEY), Ok(yyid));
+        assert_eq!(signed.as_yyid_unverified(), &yyid);
+        assert_eq!(signed.verify(&[0; 32]).unwrap_err().kind(), ErrorKind::Signature);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let yyid = Yyid::new();
+        let short = SignedYyid::<4>::sign(yyid, &KEY);
+        let long = SignedYyid::<32>::sign(yyid, &KEY);
+
+        assert_eq!(short.to_string().len(), 40);
+        assert_eq!(long.to_string().len(), 96);
+        assert!(short.to_string().starts_with(&yyid.as_simple().to_string()));
+        assert_eq!(short.to_string().parse(), Ok(short));
+        assert_eq!(format!("{long:X}").parse(), Ok(long));
+        assert_eq!(long.to_string()[..40], short.to_string());
+    }
+
+    #[test]
+    fn test_base64url_round_trip() {
+        let yyid = Yyid::new();
+        let short = SignedYyid::<4>::sign(yyid, &KEY);
+        let default = SignedYyid::<8>::sign(yyid, &KEY);
+        let long = SignedYyid::<32>::sign(yyid, &KEY);
+
+        let short_token = short.encode_base64url(&mut [0; 64]).to_string();
+        let default_token = default.encode_base64url(&mut [0; 64]).to_string();
+        let long_token = long.encode_base64url(&mut [0; 64]).to_string();
+
+        assert_eq!(short_token.len(), 27);
+        assert_eq!(default_token.len(), 32);
+        assert_eq!(long_token.len(), 64);
+        assert!(default_token.starts_with(&yyid.as_base64url().to_string()[..21]));
+        assert_eq!(SignedYyid::parse_base64url(&short_token), Ok(short));
+        assert_eq!(SignedYyid::parse_base64url(&default_token), Ok(default));
+        assert_eq!(SignedYyid::parse_base64url(&long_token), Ok(long));
+    }
+
+    #[test]
+    fn test_base64url_parse_errors() {
+        let err = |s| SignedYyid::<4>::parse_base64url(s).unwrap_err().kind();
+
+        assert_eq!(err("abc"), ErrorKind::FormatLength { expected: 27, len: 3 });
+        assert_eq!(
+            err("xJt59SLU3ELyFPQgnIDQSA+AAAA"),
+            ErrorKind::Digit {
+                character: '+',
+                index: 22,
+                expected: "[A-Za-z0-9-_]"
+            }
+        );
+        // 20 bytes leave 2 padding bits in the last digit
+        assert_eq!(
+            err("xJt59SLU3ELyFPQgnIDQSAAAAAB"),
+            ErrorKind::Digit {
+                character: 'B',
+                index: 26,
+                expected: "one of [AEIMQUYcgkosw048]"
+            }
+        );
+        assert!(SignedYyid::<4>::parse_base64url("xJt59SLU3ELyFPQgnIDQSAAAAAE").is_ok());
+    }
+
+    #[test]
+    fn test_tampered() {
+        let signed = SignedYyid::<8>::sign(Yyid::new(), &KEY);
+        let mut token = signed.to_string().into_bytes();
+        token[5] = if token[5] == b'0' { b'1' } else { b'0' };
+        let tampered: SignedYyid = std::str::from_utf8(&token).unwrap().parse().unwrap();
+
+        assert_eq!(tampered.verify(&KEY).unwrap_err().kind(), ErrorKind::Signature);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "abc".parse::<SignedYyid>().unwrap_err().kind(),
+            ErrorKind::FormatLength { expected: 48, len: 3 }
+        );
+        assert_eq!(
+            "c49b79f522d4dc42f214f4209c80d048c49b79f522d4dc4x"
+                .parse::<SignedYyid>()
+                .unwrap_err()
+                .kind(),
+            ErrorKind::Digit {
+                character: 'x',
+                index: 47,
+                expected: "[0-9a-fA-F]"
+            }
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Time-ordered YYIDs
+//!
+//! A time-ordered YYID stores the milliseconds since the Unix epoch in its
+//! most significant 48 bits, followed by 80 random bits:
+//!
+//! ```text
+//! tttttttt-tttt-rrrr-rrrr-rrrrrrrrrrrr
+//! ```
+//!
+//! Unlike UUIDv7 no bits are spent on version and variant. YYIDs created in
+//! later milliseconds sort after earlier ones, which keeps B-tree indexes
+//! compact. Within the same millisecond the order is random; use a
+//! [`MonotonicGenerator`](crate::monotonic::MonotonicGenerator) if strict
+//! ordering is required.
+//!
+//! Reading the system time requires the `std` feature. Without it, combine
+//! [`Yyid::try_new`] with [`Yyid::with_timestamp_ms`] and a clock of your own:
+//!
+//! ```rust
+//! # fn now_ms() -> u64 { 1_700_000_000_000 }
+//! # fn main() -> Result<(), yyid::Error> {
+//! use yyid::Yyid;
+//!
+//! let yyid = Yyid::try_new()?.with_timestamp_ms(now_ms());
+//! # assert_eq!(yyid.timestamp_ms(), 1_700_000_000_000);
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(feature = "std")]
+use crate::Error;
+use crate::Yyid;
+
+/// The largest timestamp that fits into 48 bits.
+pub(crate) const MAX_TIMESTAMP_MS: u64 = (1 << 48) - 1;
+
+impl Yyid {
+    /// Creates a new time-ordered YYID from the current system time.
+    ///
+    /// Requires the `std` feature; `no_std` code can use
+    /// `Yyid::try_new()?.with_timestamp_ms(..)` instead.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::Yyid;
+    ///
+    /// let first = Yyid::new_time_ordered();
+    /// std::thread::sleep(std::time::Duration::from_millis(2));
+    /// let second = Yyid::new_time_ordered();
+    ///
+    /// assert!(first < second);
+    /// assert!(first.timestamp_ms() < second.timestamp_ms());
+    /// ```
+    ///
+    /// ### Panics
+    /// Panics if the source of randomness fails; use
+    /// [`Yyid::try_new_time_ordered`] to handle this case.
+    #[cfg(feature = "std")]
+    pub fn new_time_ordered() -> Self {
+        Self::try_new_time_ordered().unwrap_or_else(|err| panic!("could not create a new YYID: {}", err))
+    }
+
+    /// Creates a new time-ordered YYID from the current system time, returning
+    /// an error if no random bytes could be retrieved.
+    ///
+    /// Requires the `std` feature; `no_std` code can use
+    /// `Yyid::try_new()?.with_timestamp_ms(..)` instead.
+    #[cfg(feature = "std")]
+    pub fn try_new_time_ordered() -> Result<Self, Error> {
+        Ok(Self::try_new()?.with_timestamp_ms(now_ms()))
+    }
+
+    /// Returns a copy of the YYID with the most significant 48 bits replaced by
+    /// `timestamp_ms`, the milliseconds since the Unix epoch.
+    ///
+    /// This allows creating time-ordered YYIDs with a custom clock, also in
+    /// `no_std` environments. Timestamps beyond 48 bits are saturated.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::Yyid;
+    ///
+    /// let yyid = Yyid::new().with_timestamp_ms(1_700_000_000_000);
+    /// assert_eq!(yyid.timestamp_ms(), 1_700_000_000_000);
+    /// assert!(yyid.to_string().starts_with("018bcfe5-6800-"));
+    /// ```
+    pub const fn with_timestamp_ms(self, timestamp_ms: u64) -> Self {
+        let ts = if timestamp_ms > MAX_TIMESTAMP_MS {
+            MAX_TIMESTAMP_MS
+        } else {
+            timestamp_ms
+        }
+        .to_be_bytes();
+
+        let mut bytes = self.0;
+        let mut i = 0;
+        while i < 6 {
+            bytes[i] = ts[i + 2];
+            i += 1;
+        }
+        Yyid(bytes)
+    }
+
+    /// Interprets the most significant 48 bits as milliseconds since the Unix
+    /// epoch, as stored by [`Yyid::with_timestamp_ms`].
+    ///
+    /// This is only meaningful for time-ordered YYIDs; for random ones the
+    /// result is random as well.
+    pub const fn timestamp_ms(&self) -> u64 {
+        let b = &self.0;
+        u64::from_be_bytes([0, 0, b[0], b[1], b[2], b[3], b[4], b[5]])
+    }
+}
+
+/// Milliseconds since the Unix epoch, or 0 if the clock is set before it.
+#[cfg(feature = "std")]
+pub(crate) fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis().min(MAX_TIMESTAMP_MS as u128) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{std::string::ToString, *};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_with_timestamp_ms() {
+        let yyid = Yyid::new();
+        let timed = yyid.with_timestamp_ms(0x0123_4567_89ab);
+
+        assert_eq!(timed.timestamp_ms(), 0x0123_4567_89ab);
+        assert_eq!(&timed.as_bytes()[..6], &[0x01, 0x23, 0x45, 0x67, 0x89, 0xab]);
+        assert_eq!(&timed.as_bytes()[6..], &yyid.as_bytes()[6..]);
+        assert_eq!(yyid.with_timestamp_ms(u64::MAX).timestamp_ms(), (1 << 48) - 1);
+    }
+
+    #[test]
+    fn test_sorted_by_timestamp() {
+        let earlier = Yyid::new().with_timestamp_ms(1_700_000_000_000);
+        let later = Yyid::new().with_timestamp_ms(1_700_000_000_001);
+
+        assert!(earlier < later);
+        assert!(earlier.to_string() < later.to_string());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_new_time_ordered() {
+        let before = time::now_ms();
+        let yyid = Yyid::new_time_ordered();
+        let after = time::now_ms();
+
+        assert!((before..=after).contains(&yyid.timestamp_ms()));
+    }
+}
@@ -1,6 +1,6 @@
 //! Conversions between UUIDs and YYIDs
 
-use super::Yyid;
+use super::{Error, ErrorKind, UuidVariant, Yyid};
 use uuid::Uuid;
 
 impl From<Uuid> for Yyid {
@@ -19,11 +19,110 @@ impl From<&Uuid> for Yyid {
     }
 }
 
-// TODO: TryFrom's for YYID->UUID (fallible, since not all YYIDs are also valid UUIDs)
+/// Converts a [`Yyid`] into a [`Uuid`], if it is a valid [RFC 9562] UUID.
+///
+/// That is the case for the nil and max UUIDs, and otherwise requires the
+/// variant bits to be `10` and the version to be one of 1 to 8. Only one
+/// in eight random YYIDs passes this check.
+///
+/// [RFC 9562]: https://www.rfc-editor.org/rfc/rfc9562
+///
+/// ### Example
+/// ```rust
+/// use uuid::Uuid;
+/// use yyid::Yyid;
+///
+/// let yyid: Yyid = "c49b79f5-22d4-4c42-b214-f4209c80d048".parse().unwrap();
+/// let uuid = Uuid::try_from(yyid).unwrap();
+/// assert_eq!(uuid.get_version_num(), 4);
+///
+/// let yyid: Yyid = "c49b79f5-22d4-dc42-f214-f4209c80d048".parse().unwrap();
+/// assert!(Uuid::try_from(yyid).is_err());
+/// ```
+impl TryFrom<Yyid> for Uuid {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(f: Yyid) -> Result<Self, Self::Error> {
+        Uuid::try_from(&f)
+    }
+}
+
+impl TryFrom<&Yyid> for Uuid {
+    type Error = Error;
+
+    fn try_from(f: &Yyid) -> Result<Self, Self::Error> {
+        let bytes = f.as_bytes();
+        if f.is_nil() || bytes == &[0xff; 16] {
+            return Ok(Uuid::from_bytes(*bytes));
+        }
+
+        if f.uuid_variant() != UuidVariant::Rfc9562 {
+            return Err(Error(ErrorKind::UuidVariant { bits: bytes[8] >> 5 }));
+        }
+        if f.uuid_version().is_none() {
+            return Err(Error(ErrorKind::UuidVersion { version: bytes[6] >> 4 }));
+        }
+
+        Ok(Uuid::from_bytes(*bytes))
+    }
+}
+
+impl Yyid {
+    /// Converts the [`Yyid`] into a version 4 [`Uuid`], overwriting the six
+    /// version and variant bits.
+    ///
+    /// The remaining 122 bits are kept as they are:
+    ///
+    /// | Octet | Bits        | [`Yyid`] | [`Uuid`] (v4)                 |
+    /// |-------|-------------|----------|-------------------------------|
+    /// | 6     | `0xf0`      | random   | `0100` (version 4)            |
+    /// | 8     | `0xc0`      | random   | `10` (RFC 9562 variant)       |
+    /// | other | all         | random   | unchanged                     |
+    ///
+    /// As 64 YYIDs map onto the same UUID, the conversion cannot be reversed:
+    /// `Yyid::from(uuid)` only returns the original if it was already
+    /// [compatible](Yyid::is_uuid_v4_compatible). When a UUID received from a
+    /// partner must be looked up again, store it next to the YYID instead of
+    /// deriving one from the other.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::Yyid;
+    ///
+    /// let yyid: Yyid = "c49b79f5-22d4-dc42-f214-f4209c80d048".parse().unwrap();
+    /// let uuid = yyid.to_uuid_v4_lossy();
+    ///
+    /// assert_eq!(uuid.to_string(), "c49b79f5-22d4-4c42-b214-f4209c80d048");
+    /// assert_eq!(uuid.get_version_num(), 4);
+    /// ```
+    pub const fn to_uuid_v4_lossy(self) -> Uuid {
+        let mut bytes = self.0;
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        Uuid::from_bytes(bytes)
+    }
+
+    /// Tests if the [`Yyid`] is already a valid version 4 [`Uuid`], so
+    /// [`Yyid::to_uuid_v4_lossy`] returns it unchanged.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use yyid::Yyid;
+    ///
+    /// let yyid: Yyid = "c49b79f5-22d4-4c42-b214-f4209c80d048".parse().unwrap();
+    /// assert!(yyid.is_uuid_v4_compatible());
+    /// assert_eq!(Yyid::from(yyid.to_uuid_v4_lossy()), yyid);
+    /// ```
+    pub fn is_uuid_v4_compatible(&self) -> bool {
+        self.uuid_version() == Some(4)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use crate::{std::string::ToString, *};
+    use ::uuid::{Uuid, Variant};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -66,4 +165,53 @@ mod tests {
 
         assert_eq!(uuid_s, yyid_s);
     }
+
+    #[test]
+    fn test_uuid_try_from_yyid() {
+        let uuid = Uuid::new_v4();
+        let yyid = Yyid::from(uuid);
+
+        assert_eq!(Uuid::try_from(yyid), Ok(uuid));
+        assert_eq!(Uuid::try_from(&yyid), Ok(uuid));
+        assert_eq!(Uuid::try_from(Yyid::nil()), Ok(Uuid::nil()));
+        assert_eq!(Uuid::try_from(Yyid::from(Uuid::max())), Ok(Uuid::max()));
+    }
+
+    #[test]
+    fn test_uuid_try_from_invalid_yyid() {
+        let yyid: Yyid = "c49b79f5-22d4-4c42-f214-f4209c80d048".parse().unwrap();
+        let err = Uuid::try_from(yyid).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UuidVariant { bits: 0b111 });
+        assert_eq!(
+            err.to_string(),
+            "not a valid UUID: expected the RFC 9562 variant `10x`, found `111`"
+        );
+
+        let yyid: Yyid = "c49b79f5-22d4-dc42-b214-f4209c80d048".parse().unwrap();
+        let err = Uuid::try_from(yyid).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UuidVersion { version: 0xd });
+    }
+
+    #[test]
+    fn test_to_uuid_v4_lossy() {
+        for _ in 0..100 {
+            let yyid = Yyid::new();
+            let uuid = yyid.to_uuid_v4_lossy();
+
+            assert_eq!(uuid.get_version_num(), 4);
+            assert_eq!(uuid.get_variant(), Variant::RFC4122);
+            assert_eq!(Yyid::from(uuid).to_uuid_v4_lossy(), uuid);
+            assert!(Yyid::from(uuid).is_uuid_v4_compatible());
+            assert_eq!(yyid.is_uuid_v4_compatible(), Yyid::from(uuid) == yyid);
+        }
+    }
+
+    #[test]
+    fn test_is_uuid_v4_compatible() {
+        assert!(Yyid::from(Uuid::new_v4()).is_uuid_v4_compatible());
+        assert!(!Yyid::nil().is_uuid_v4_compatible());
+
+        let yyid: Yyid = "c49b79f5-22d4-4c42-f214-f4209c80d048".parse().unwrap();
+        assert!(!yyid.is_uuid_v4_compatible());
+    }
 }